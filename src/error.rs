@@ -0,0 +1,322 @@
+use std::{fmt, ops::Range};
+
+use crate::model::{ComponentName, ParameterName, PropertyName};
+
+/// An error that stops the parsing of an iCalendar stream.
+///
+/// Besides the kind, it tells where the error was found: the physical line
+/// and column in the input (folded lines are counted as they are in the
+/// input), and the component and the property it was found in.
+#[derive(Debug, PartialEq)]
+pub struct ParseError(pub(crate) Box<ParseErrorInner>);
+
+// boxed to keep `Result<_, ParseError>` small
+#[derive(Debug, PartialEq)]
+pub(crate) struct ParseErrorInner {
+    pub(crate) kind: ParseErrorKind,
+    pub(crate) lines: Range<usize>,
+    pub(crate) position: (usize, usize),
+    pub(crate) component: Option<ComponentName>,
+    pub(crate) property: Option<PropertyName>,
+    pub(crate) expected: Option<String>,
+    pub(crate) found: Option<String>,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, lines: Range<usize>) -> Self {
+        Self(Box::new(ParseErrorInner {
+            kind,
+            position: (lines.start, 1),
+            lines,
+            component: None,
+            property: None,
+            expected: None,
+            found: None,
+        }))
+    }
+
+    pub(crate) fn with_position(mut self, (line, column): (usize, usize)) -> Self {
+        self.0.position = (line, column);
+        self
+    }
+
+    pub(crate) fn with_component(mut self, component: Option<ComponentName>) -> Self {
+        self.0.component = component;
+        self
+    }
+
+    pub(crate) fn with_property(mut self, property: Option<PropertyName>) -> Self {
+        self.0.property = property;
+        self
+    }
+
+    /// Sets what was expected and what was found instead, both as they are
+    /// to be shown in the message.
+    pub(crate) fn with_mismatch(
+        mut self,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        self.0.expected = Some(expected.into());
+        self.0.found = Some(found.into());
+        self
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.0.kind
+    }
+
+    /// Returns the range of the (1-based) physical line numbers the error
+    /// was found in.
+    pub fn lines(&self) -> Range<usize> {
+        self.0.lines.clone()
+    }
+
+    /// Returns the (1-based) physical line number the error was found at.
+    pub fn line(&self) -> usize {
+        self.0.position.0
+    }
+
+    /// Returns the (1-based) column, in characters, the error was found at.
+    pub fn column(&self) -> usize {
+        self.0.position.1
+    }
+
+    /// Returns the innermost component the error was found in.
+    pub fn component(&self) -> Option<&ComponentName> {
+        self.0.component.as_ref()
+    }
+
+    /// Returns the property the error was found in.
+    pub fn property(&self) -> Option<&PropertyName> {
+        self.0.property.as_ref()
+    }
+
+    /// Returns a description of what was expected, e.g. `":"`.
+    pub fn expected(&self) -> Option<&str> {
+        self.0.expected.as_deref()
+    }
+
+    /// Returns a description of what was found instead, e.g. `end of line`.
+    pub fn found(&self) -> Option<&str> {
+        self.0.found.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line(), self.column())?;
+        match (&self.0.expected, &self.0.found) {
+            (Some(expected), Some(found)) => write!(f, "expected {}, found {}", expected, found)?,
+            _ => write!(f, "{}", self.0.kind)?,
+        }
+        // a violation names its property in the message already
+        if let (Some(property), false) = (
+            &self.0.property,
+            matches!(self.0.kind, ParseErrorKind::Validation(_)),
+        ) {
+            write!(f, " in property {}", property.as_str())?;
+        }
+        if let Some(component) = &self.0.component {
+            write!(f, " in component {}", component.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.0.kind {
+            ParseErrorKind::Validation(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// An error found by
+/// [`parse_calendar_lenient`](crate::parser::parse_calendar_lenient), which
+/// parses on after it.
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub(crate) severity: Severity,
+    pub(crate) error: ParseError,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(severity: Severity, error: ParseError) -> Self {
+        Self { severity, error }
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    pub fn into_error(self) -> ParseError {
+        self.error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    /// The input is kept, but not as RFC 5545 defines it.
+    Warning,
+    /// A part of the input is skipped, or where a component ends is
+    /// guessed.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// The line is not a valid content line.
+    InvalidContentLine,
+    /// The value is not valid for the property.
+    InvalidPropertyValue,
+    /// The value of BEGIN or END is not a valid component name.
+    InvalidComponentName,
+    /// The input does not start with "BEGIN:VCALENDAR".
+    ExpectedCalendar,
+    /// A content line follows "END:VCALENDAR".
+    ExpectedEndOfInput,
+    /// An END does not match the innermost BEGIN.
+    MismatchedEnd {
+        expected: ComponentName,
+        found: ComponentName,
+    },
+    /// The input ends before the END of the component.
+    UnterminatedComponent(ComponentName),
+    /// The component does not follow the rules for its properties.
+    Validation(ValidationError),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidContentLine => write!(f, "invalid content line"),
+            ParseErrorKind::InvalidPropertyValue => write!(f, "invalid property value"),
+            ParseErrorKind::InvalidComponentName => write!(f, "invalid component name"),
+            ParseErrorKind::ExpectedCalendar => write!(f, "expected BEGIN:VCALENDAR"),
+            ParseErrorKind::ExpectedEndOfInput => write!(f, "expected end of input"),
+            ParseErrorKind::MismatchedEnd { expected, found } => write!(
+                f,
+                "expected END:{}, found END:{}",
+                expected.as_str(),
+                found.as_str()
+            ),
+            ParseErrorKind::UnterminatedComponent(name) => {
+                write!(f, "expected END:{}, found end of input", name.as_str())
+            }
+            ParseErrorKind::Validation(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// A violation of the rules a component places on its properties.
+#[derive(Debug, PartialEq)]
+pub enum ValidationError {
+    /// A REQUIRED property does not occur.
+    MissingProperty(PropertyName),
+    /// A property that MUST NOT occur more than once occurs more than once.
+    DuplicateProperty(PropertyName),
+    /// Properties that MUST NOT occur together occur together.
+    MutuallyExclusiveProperties(PropertyName, PropertyName),
+    /// The first property occurs without the second one it requires.
+    PropertyRequires(PropertyName, PropertyName),
+    /// The value is not allowed for the property.
+    InvalidPropertyValue(PropertyName),
+    /// The value is well-formed but out of the range allowed for the
+    /// property.
+    PropertyValueOutOfRange(PropertyName),
+    /// The value of the parameter is not allowed for it.
+    InvalidParameterValue(PropertyName, ParameterName),
+    /// A parameter that MUST NOT occur more than once occurs more than
+    /// once.
+    DuplicateParameter(PropertyName, ParameterName),
+    /// A VTIMEZONE has neither STANDARD nor DAYLIGHT.
+    MissingObservance,
+    /// The component is not allowed here.
+    UnexpectedComponent(ComponentName),
+}
+
+impl ValidationError {
+    /// Returns the property the violation is about, if any.
+    pub fn property(&self) -> Option<&PropertyName> {
+        match self {
+            ValidationError::MissingProperty(name)
+            | ValidationError::DuplicateProperty(name)
+            | ValidationError::MutuallyExclusiveProperties(name, _)
+            | ValidationError::PropertyRequires(name, _)
+            | ValidationError::InvalidPropertyValue(name)
+            | ValidationError::PropertyValueOutOfRange(name)
+            | ValidationError::InvalidParameterValue(name, _)
+            | ValidationError::DuplicateParameter(name, _) => Some(name),
+            ValidationError::MissingObservance | ValidationError::UnexpectedComponent(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::MissingProperty(name) => {
+                write!(f, "missing required property {}", name.as_str())
+            }
+            ValidationError::DuplicateProperty(name) => {
+                write!(f, "property {} occurs more than once", name.as_str())
+            }
+            ValidationError::MutuallyExclusiveProperties(a, b) => write!(
+                f,
+                "properties {} and {} must not occur together",
+                a.as_str(),
+                b.as_str()
+            ),
+            ValidationError::PropertyRequires(a, b) => {
+                write!(f, "property {} requires {}", a.as_str(), b.as_str())
+            }
+            ValidationError::InvalidPropertyValue(name) => {
+                write!(f, "invalid value of property {}", name.as_str())
+            }
+            ValidationError::PropertyValueOutOfRange(name) => {
+                write!(f, "value of property {} is out of range", name.as_str())
+            }
+            ValidationError::InvalidParameterValue(property, parameter) => write!(
+                f,
+                "invalid value of parameter {} of property {}",
+                parameter.as_str(),
+                property.as_str()
+            ),
+            ValidationError::DuplicateParameter(property, parameter) => write!(
+                f,
+                "parameter {} of property {} occurs more than once",
+                parameter.as_str(),
+                property.as_str()
+            ),
+            ValidationError::MissingObservance => {
+                write!(f, "VTIMEZONE has neither STANDARD nor DAYLIGHT")
+            }
+            ValidationError::UnexpectedComponent(name) => {
+                write!(f, "component {} is not allowed here", name.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
@@ -1,57 +1,858 @@
-use std::collections::BTreeMap;
+mod alarm;
+mod attachment;
+mod binary;
+mod common_properties;
+mod date;
+mod date_time;
+mod duration;
+mod event;
+mod free_busy;
+mod geo;
+mod journal;
+mod media_type;
+mod parameter;
+mod period;
+mod time_zone;
+mod todo;
+mod uri;
+mod utc_offset;
+mod value_type;
 
-struct CalendarObject(Vec<Property>, Vec<Component>);
+use std::{
+    fmt::{self, Write},
+    ops::RangeInclusive,
+};
 
-struct Component(ComponentName, Vec<Property>, Vec<Component>);
+use nom::Parser;
 
-struct ComponentName(String);
+use crate::{
+    error::ValidationError,
+    parser::{binary, geovalue, integer, text, uri},
+};
 
-#[derive(Debug, PartialEq)]
+pub use self::alarm::{Alarm, AlarmAction, Related, Trigger};
+pub use self::attachment::{Attachment, AttachmentValue};
+pub use self::binary::Binary;
+pub use self::common_properties::CommonProperties;
+pub use self::date::{Date, Weekday};
+pub use self::date_time::{DateTime, Time, TimeForm};
+pub use self::duration::Duration;
+pub use self::event::{Event, EventStatus};
+pub use self::free_busy::{FreeBusy, FreeBusyTime, FreeBusyType};
+pub use self::geo::Geo;
+pub use self::journal::{Journal, JournalStatus};
+pub use self::media_type::MediaType;
+pub use self::parameter::{
+    CalendarUserType, Encoding, Parameter, PartStat, Range, RelationshipType, Role,
+};
+pub use self::period::Period;
+pub use self::time_zone::{Observance, ObservanceKind, TimeZone};
+pub use self::todo::{Todo, TodoStatus};
+pub use self::uri::Uri;
+pub(crate) use self::uri::UriComponents;
+pub use self::utc_offset::UtcOffset;
+pub use self::value_type::ValueType;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarObject(pub(crate) Vec<Property>, pub(crate) Vec<Component>);
+
+impl CalendarObject {
+    /// Checks that PRODID and VERSION occur exactly once and CALSCALE and
+    /// METHOD at most once.
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        required(&self.0, "PRODID")?;
+        required(&self.0, "VERSION")?;
+        optional(&self.0, "CALSCALE")?;
+        optional(&self.0, "METHOD")?;
+        Ok(())
+    }
+
+    pub fn properties(&self) -> &[Property] {
+        &self.0
+    }
+
+    pub fn components(&self) -> &[Component] {
+        &self.1
+    }
+
+    /// Returns the VEVENT components, typed.
+    ///
+    /// A VEVENT that does not follow the rules for its properties, as
+    /// [`parse_calendar_lenient`](crate::parser::parse_calendar_lenient)
+    /// keeps it, is returned as the violation instead of being skipped; it
+    /// is still available untyped from [`CalendarObject::components`].
+    pub fn events(&self) -> impl Iterator<Item = Result<Event, ValidationError>> {
+        self.typed_components("VEVENT")
+    }
+
+    /// Returns the VTODO components, typed, like [`CalendarObject::events`].
+    pub fn todos(&self) -> impl Iterator<Item = Result<Todo, ValidationError>> {
+        self.typed_components("VTODO")
+    }
+
+    /// Returns the VJOURNAL components, typed, like
+    /// [`CalendarObject::events`].
+    pub fn journals(&self) -> impl Iterator<Item = Result<Journal, ValidationError>> {
+        self.typed_components("VJOURNAL")
+    }
+
+    /// Returns the VFREEBUSY components, typed, like
+    /// [`CalendarObject::events`].
+    pub fn free_busys(&self) -> impl Iterator<Item = Result<FreeBusy, ValidationError>> {
+        self.typed_components("VFREEBUSY")
+    }
+
+    /// Returns the VTIMEZONE components, typed, like
+    /// [`CalendarObject::events`].
+    pub fn time_zones(&self) -> impl Iterator<Item = Result<TimeZone, ValidationError>> {
+        self.typed_components("VTIMEZONE")
+    }
+
+    /// Returns the valid VTIMEZONE referred to by the TZID parameter value.
+    pub fn time_zone(&self, tzid: &str) -> Option<TimeZone> {
+        self.time_zones()
+            .filter_map(Result::ok)
+            .find(|time_zone| time_zone.tzid().as_str() == tzid)
+    }
+
+    fn typed_components<'a, T>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = Result<T, ValidationError>> + 'a
+    where
+        T: TryFrom<&'a Component, Error = ValidationError> + 'a,
+    {
+        self.1
+            .iter()
+            .filter(move |component| component.name().as_str() == name)
+            .map(T::try_from)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Component(
+    pub(crate) ComponentName,
+    pub(crate) Vec<Property>,
+    pub(crate) Vec<Component>,
+);
+
+impl Component {
+    pub fn name(&self) -> &ComponentName {
+        &self.0
+    }
+
+    pub fn properties(&self) -> &[Property] {
+        &self.1
+    }
+
+    pub fn components(&self) -> &[Component] {
+        &self.2
+    }
+
+    /// Checks the properties and the sub-components of the components
+    /// defined by RFC 5545.
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        match self.0.as_str() {
+            "VEVENT" => Event::try_from(self).map(|_| ()),
+            "VTODO" => Todo::try_from(self).map(|_| ()),
+            "VJOURNAL" => Journal::try_from(self).map(|_| ()),
+            "VFREEBUSY" => FreeBusy::try_from(self).map(|_| ()),
+            "VTIMEZONE" => TimeZone::try_from(self).map(|_| ()),
+            "VALARM" => Alarm::try_from(self).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The name of a component. Names are case-insensitive, so the name is
+/// kept in uppercase, along with the original spelling if it differs.
+#[derive(Clone, Debug)]
+pub struct ComponentName(pub(crate) String, pub(crate) Option<String>);
+
+impl ComponentName {
+    pub fn new(name: &str) -> Self {
+        let (name, original) = canonicalize(name);
+        Self(name, original)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the name as it was spelled.
+    pub fn original(&self) -> &str {
+        self.1.as_deref().unwrap_or(&self.0)
+    }
+}
+
+impl PartialEq for ComponentName {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for ComponentName {}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Property(
     pub(crate) PropertyName,
-    pub(crate) BTreeMap<ParameterName, ParameterValue>,
+    pub(crate) Parameters,
     pub(crate) PropertyValue,
 );
 
-#[derive(Debug, PartialEq)]
-pub struct PropertyName(pub(crate) String);
+impl Property {
+    pub(crate) fn new(
+        name: String,
+        params: Vec<(String, Vec<String>)>,
+        value: PropertyValue,
+    ) -> Self {
+        Self(
+            PropertyName::new(&name),
+            params
+                .into_iter()
+                .map(|(name, values)| (ParameterName::new(&name), ParameterValue(values)))
+                .collect(),
+            value,
+        )
+    }
+
+    pub fn name(&self) -> &PropertyName {
+        &self.0
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.1
+    }
+
+    /// Returns the first parameter with the name.
+    pub fn parameter(&self, name: &str) -> Option<&ParameterValue> {
+        self.1.get(name)
+    }
+
+    /// Types the parameters, see [`Parameter::new`].
+    ///
+    /// The parameters defined by RFC 5545 MUST NOT occur more than once.
+    pub fn typed_parameters(&self) -> Result<Vec<Parameter>, ValidationError> {
+        self.1
+            .iter()
+            .enumerate()
+            .map(|(i, (name, value))| {
+                let parameter = Parameter::new(name, value).ok_or_else(|| {
+                    ValidationError::InvalidParameterValue(self.0.clone(), name.clone())
+                })?;
+                if !matches!(parameter, Parameter::Other(..))
+                    && self.1.0[..i].iter().any(|(other, _)| other == name)
+                {
+                    return Err(ValidationError::DuplicateParameter(
+                        self.0.clone(),
+                        name.clone(),
+                    ));
+                }
+                Ok(parameter)
+            })
+            .collect()
+    }
+
+    pub fn value(&self) -> &PropertyValue {
+        &self.2
+    }
+}
+
+/// Writes the property as a content line, folded so that no line is longer
+/// than 75 octets.
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut line = self.0.original().to_owned();
+        for (name, value) in &self.1 {
+            write!(line, ";{}=", name.original())?;
+            for (i, value) in value.0.iter().enumerate() {
+                if i > 0 {
+                    line.push(',');
+                }
+                let value = encode_param_value(value);
+                if value.contains([':', ';', ',']) {
+                    write!(line, "\"{}\"", value)?;
+                } else {
+                    line.push_str(&value);
+                }
+            }
+        }
+        write!(line, ":{}", self.2)?;
+
+        let mut octets = 0;
+        for c in line.chars() {
+            if octets + c.len_utf8() > 75 {
+                write!(f, "\r\n ")?;
+                octets = 1;
+            }
+            octets += c.len_utf8();
+            write!(f, "{}", c)?;
+        }
+        write!(f, "\r\n")
+    }
+}
+
+/// Applies the caret encoding to a parameter value, so that a newline and
+/// DQUOTE can be written.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6868#section-3>
+fn encode_param_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '^' => encoded.push_str("^^"),
+            '\n' => encoded.push_str("^n"),
+            '"' => encoded.push_str("^'"),
+            c => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// The name of a property, kept in uppercase like [`ComponentName`].
+#[derive(Clone, Debug)]
+pub struct PropertyName(pub(crate) String, pub(crate) Option<String>);
+
+impl PropertyName {
+    pub fn new(name: &str) -> Self {
+        let (name, original) = canonicalize(name);
+        Self(name, original)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the name as it was spelled.
+    pub fn original(&self) -> &str {
+        self.1.as_deref().unwrap_or(&self.0)
+    }
+}
+
+impl PartialEq for PropertyName {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for PropertyName {}
+
+/// The name of a parameter, kept in uppercase like [`ComponentName`].
+#[derive(Clone, Debug)]
+pub struct ParameterName(pub(crate) String, pub(crate) Option<String>);
+
+impl ParameterName {
+    pub fn new(name: &str) -> Self {
+        let (name, original) = canonicalize(name);
+        Self(name, original)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the name as it was spelled.
+    pub fn original(&self) -> &str {
+        self.1.as_deref().unwrap_or(&self.0)
+    }
+}
+
+impl PartialEq for ParameterName {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for ParameterName {}
+
+/// Returns whether the name or value is an x-name, i.e. starts with "X-"
+/// in any case.
+pub(crate) fn is_x_name(s: &str) -> bool {
+    s.get(..2)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("X-"))
+}
+
+/// Returns the name in uppercase, and the name as it is if it differs.
+fn canonicalize(name: &str) -> (String, Option<String>) {
+    let canonical = name.to_ascii_uppercase();
+    let original = (canonical != name).then(|| name.to_owned());
+    (canonical, original)
+}
+
+/// The parameters of a property, in the order they occur in the content
+/// line. A parameter that occurs more than once is kept each time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Parameters(pub(crate) Vec<(ParameterName, ParameterValue)>);
+
+impl Parameters {
+    /// Returns the first parameter with the name.
+    pub fn get(&self, name: &str) -> Option<&ParameterValue> {
+        self.0
+            .iter()
+            .find(|(n, _)| n.as_str().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the parameters with the name, in order.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a ParameterValue> {
+        self.0
+            .iter()
+            .filter(move |(n, _)| n.as_str().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
 
-#[derive(Debug, PartialEq)]
-pub struct ParameterName(String);
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
 
-#[derive(Debug, PartialEq)]
-pub struct ParameterValue(Vec<String>);
+    /// Returns the names of the parameters that occur more than once, each
+    /// once, in the order of their second occurrence.
+    pub fn duplicates(&self) -> Vec<&ParameterName> {
+        let mut duplicates: Vec<&ParameterName> = vec![];
+        for (i, (name, _)) in self.0.iter().enumerate() {
+            if self.0[..i].iter().any(|(other, _)| other == name) && !duplicates.contains(&name) {
+                duplicates.push(name);
+            }
+        }
+        duplicates
+    }
 
-#[derive(Debug, PartialEq)]
+    pub fn iter(&self) -> impl Iterator<Item = &(ParameterName, ParameterValue)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(ParameterName, ParameterValue)> for Parameters {
+    fn from_iter<T: IntoIterator<Item = (ParameterName, ParameterValue)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Parameters {
+    type Item = &'a (ParameterName, ParameterValue);
+    type IntoIter = std::slice::Iter<'a, (ParameterName, ParameterValue)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterValue(pub(crate) Vec<String>);
+
+impl ParameterValue {
+    pub fn values(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Parses the values as URIs, as for the ALTREP, DELEGATED-FROM,
+    /// DELEGATED-TO, DIR, MEMBER and SENT-BY parameters.
+    ///
+    /// Returns `None` if any of them is not a URI.
+    pub fn uris(&self) -> Option<Vec<Uri>> {
+        self.0.iter().map(|value| Uri::new(value)).collect()
+    }
+
+    /// Parses the value as a media type, as for the FMTTYPE parameter.
+    ///
+    /// Returns `None` unless there is exactly one value and it is a media
+    /// type.
+    pub fn media_type(&self) -> Option<MediaType> {
+        match self.0.as_slice() {
+            [value] => MediaType::new(value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum PropertyValue {
     Binary(Binary),
     Boolean(bool),
     CalAddress(CalendarUserAddress),
-    Date(String),
-    DateTime(String),
-    Duration(String),
+    Date(Date),
+    DateTime(DateTime),
+    Duration(Duration),
     Float(f64),
+    Geo(Geo),
     Integer(i32),
-    Period(String),
+    /// The values of a property that MAY have more than one value,
+    /// separated by commas.
+    List(Vec<PropertyValue>),
+    Period(Period),
     Recur(String),
+    /// The components of a structured value, separated by semicolons.
+    Structured(Vec<PropertyValue>),
     Text(Text),
-    Time(String),
-    Uri(String),
-    UtcOffset(String),
+    Time(Time),
+    Uri(Uri),
+    UtcOffset(UtcOffset),
     XType(String),
 }
 
-#[derive(Debug, PartialEq)]
+impl PropertyValue {
+    /// Returns the text of a TEXT value or of a value not typed yet.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Text(Text(s)) | PropertyValue::XType(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the value as it appears in a content line, escaping TEXT.
+impl fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyValue::Binary(binary) => write!(f, "{}", binary),
+            PropertyValue::Boolean(boolean) => {
+                write!(f, "{}", if *boolean { "TRUE" } else { "FALSE" })
+            }
+            PropertyValue::CalAddress(CalendarUserAddress(s))
+            | PropertyValue::Recur(s)
+            | PropertyValue::XType(s) => write!(f, "{}", s),
+            PropertyValue::Date(date) => write!(f, "{}", date),
+            PropertyValue::DateTime(date_time) => write!(f, "{}", date_time),
+            PropertyValue::Duration(duration) => write!(f, "{}", duration),
+            PropertyValue::Float(float) => write!(f, "{}", float),
+            PropertyValue::Geo(geo) => write!(f, "{};{}", geo.0, geo.1),
+            PropertyValue::Integer(integer) => write!(f, "{}", integer),
+            PropertyValue::List(values) => write_separated(f, values, ','),
+            PropertyValue::Period(period) => write!(f, "{}", period),
+            PropertyValue::Structured(values) => write_separated(f, values, ';'),
+            PropertyValue::Text(Text(s)) => {
+                for c in s.chars() {
+                    match c {
+                        '\\' | ';' | ',' => write!(f, "\\{}", c)?,
+                        '\n' => write!(f, "\\n")?,
+                        c => write!(f, "{}", c)?,
+                    }
+                }
+                Ok(())
+            }
+            PropertyValue::Time(time) => write!(f, "{}", time),
+            PropertyValue::Uri(uri) => write!(f, "{}", uri),
+            PropertyValue::UtcOffset(utc_offset) => write!(f, "{}", utc_offset),
+        }
+    }
+}
+
+fn write_separated(
+    f: &mut fmt::Formatter<'_>,
+    values: &[PropertyValue],
+    separator: char,
+) -> fmt::Result {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", separator)?;
+        }
+        write!(f, "{}", value)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Text(pub(crate) String);
 
-#[derive(Debug, PartialEq)]
-pub struct Binary(pub(crate) String);
+impl Text {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&Property> for Text {
+    type Error = ValidationError;
+
+    /// Reads a TEXT property value, unescaping it.
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let invalid = || ValidationError::InvalidPropertyValue(property.0.clone());
+        match property.value() {
+            PropertyValue::Text(text) => Ok(text.clone()),
+            PropertyValue::XType(s) => nom::combinator::all_consuming(text)
+                .parse(s.as_str())
+                .map(|(_, text)| text)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Boolean {
     False,
     True,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CalendarUserAddress(pub(crate) String);
+
+impl CalendarUserAddress {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Checks that the property occurs exactly once, and returns it.
+pub(crate) fn required<'a>(
+    properties: &'a [Property],
+    name: &str,
+) -> Result<&'a Property, ValidationError> {
+    let mut found = properties.iter().filter(|p| p.name().as_str() == name);
+    match (found.next(), found.next()) {
+        (None, _) => Err(ValidationError::MissingProperty(PropertyName::new(name))),
+        (Some(property), None) => Ok(property),
+        (Some(_), Some(_)) => Err(ValidationError::DuplicateProperty(PropertyName::new(name))),
+    }
+}
+
+/// Checks that the property occurs at most once.
+pub(crate) fn optional(properties: &[Property], name: &str) -> Result<(), ValidationError> {
+    match properties
+        .iter()
+        .filter(|p| p.name().as_str() == name)
+        .count()
+    {
+        0 | 1 => Ok(()),
+        _ => Err(ValidationError::DuplicateProperty(PropertyName::new(name))),
+    }
+}
+
+/// Checks that the properties do not occur together.
+pub(crate) fn exclusive(properties: &[Property], a: &str, b: &str) -> Result<(), ValidationError> {
+    match (find(properties, a), find(properties, b)) {
+        (Some(_), Some(_)) => Err(ValidationError::MutuallyExclusiveProperties(
+            PropertyName::new(a),
+            PropertyName::new(b),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Replaces the properties with the same name, or appends the property.
+pub(crate) fn replace(properties: &mut Vec<Property>, property: Property) {
+    match properties.iter().position(|p| p.name() == property.name()) {
+        Some(index) => {
+            let rest = properties.split_off(index + 1);
+            properties.extend(rest.into_iter().filter(|p| p.name() != property.name()));
+            properties[index] = property;
+        }
+        None => properties.push(property),
+    }
+}
+
+/// Removes the properties with the name.
+pub(crate) fn remove(properties: &mut Vec<Property>, name: &str) {
+    properties.retain(|p| p.name().as_str() != name);
+}
+
+/// Returns the first property with the name.
+pub(crate) fn find<'a>(properties: &'a [Property], name: &str) -> Option<&'a Property> {
+    properties.iter().find(|p| p.name().as_str() == name)
+}
+
+/// Returns the properties with the name.
+pub(crate) fn filter<'a>(
+    properties: &'a [Property],
+    name: &'a str,
+) -> impl Iterator<Item = &'a Property> {
+    properties.iter().filter(move |p| p.name().as_str() == name)
+}
+
+/// Types the INTEGER and FLOAT values not typed yet, checking them against
+/// the range the property allows.
+pub(crate) fn decode_number(property: Property) -> Result<Property, ValidationError> {
+    match property.name().as_str() {
+        "PRIORITY" => decode_integer(property, 0..=9),
+        "PERCENT-COMPLETE" => decode_integer(property, 0..=100),
+        "SEQUENCE" => decode_integer(property, 0..=i32::MAX),
+        "REPEAT" => decode_integer(property, 0..=i32::MAX),
+        "GEO" => decode_geo(property),
+        _ => Ok(property),
+    }
+}
+
+/// Types the TEXT values not typed yet, unescaping them.
+pub(crate) fn decode_text(property: Property) -> Result<Property, ValidationError> {
+    if !matches!(
+        property.name().as_str(),
+        "COMMENT" | "CONTACT" | "DESCRIPTION" | "LOCATION" | "SUMMARY" | "TZNAME" | "UID"
+    ) {
+        return Ok(property);
+    }
+    let text = Text::try_from(&property)?;
+    let Property(name, params, _) = property;
+    Ok(Property(name, params, PropertyValue::Text(text)))
+}
+
+fn decode_integer(
+    property: Property,
+    range: RangeInclusive<i32>,
+) -> Result<Property, ValidationError> {
+    let Property(name, params, value) = property;
+    let number = match value {
+        PropertyValue::Integer(number) => number,
+        PropertyValue::XType(s) => {
+            match nom::combinator::all_consuming(integer).parse(s.as_str()) {
+                Ok((_, number)) => number,
+                // the digits are well-formed, but do not fit in an i32
+                Err(nom::Err::Error(e)) if e.code == nom::error::ErrorKind::MapRes => {
+                    return Err(ValidationError::PropertyValueOutOfRange(name));
+                }
+                Err(_) => return Err(ValidationError::InvalidPropertyValue(name)),
+            }
+        }
+        _ => return Err(ValidationError::InvalidPropertyValue(name)),
+    };
+    if !range.contains(&number) {
+        return Err(ValidationError::PropertyValueOutOfRange(name));
+    }
+    Ok(Property(name, params, PropertyValue::Integer(number)))
+}
+
+fn decode_geo(property: Property) -> Result<Property, ValidationError> {
+    let Property(name, params, value) = property;
+    let geo = match value {
+        PropertyValue::Geo(geo) => geo,
+        PropertyValue::XType(s) => {
+            let (_, (latitude, longitude)) = nom::combinator::all_consuming(geovalue)
+                .parse(s.as_str())
+                .map_err(|_| ValidationError::InvalidPropertyValue(name.clone()))?;
+            Geo::new(latitude, longitude)
+                .ok_or_else(|| ValidationError::PropertyValueOutOfRange(name.clone()))?
+        }
+        _ => return Err(ValidationError::InvalidPropertyValue(name)),
+    };
+    Ok(Property(name, params, PropertyValue::Geo(geo)))
+}
+
+/// Checks the parameters of ATTACH: FMTTYPE is a media type, and
+/// `ENCODING=BASE64` and `VALUE=BINARY` occur together. Types the value not
+/// typed yet as BINARY if they do.
+pub(crate) fn decode_attach(property: Property) -> Result<Property, ValidationError> {
+    if property.name().as_str() != "ATTACH" {
+        return Ok(property);
+    }
+    let invalid = |name: &str| {
+        ValidationError::InvalidParameterValue(property.0.clone(), ParameterName::new(name))
+    };
+    if property
+        .parameter("FMTTYPE")
+        .is_some_and(|value| value.media_type().is_none())
+    {
+        return Err(invalid("FMTTYPE"));
+    }
+    let encoding = property.parameter("ENCODING").map(ParameterValue::values);
+    let is_binary = match property.parameter("VALUE").map(ParameterValue::values) {
+        None => false,
+        Some([value_type]) if value_type.eq_ignore_ascii_case("URI") => false,
+        Some([value_type]) if value_type.eq_ignore_ascii_case("BINARY") => true,
+        Some(_) => return Err(invalid("VALUE")),
+    };
+    match (encoding, is_binary) {
+        (None, false) => {}
+        (Some([encoding]), false) if encoding.eq_ignore_ascii_case("8BIT") => {}
+        (Some([encoding]), true) if encoding.eq_ignore_ascii_case("BASE64") => {}
+        _ => return Err(invalid("ENCODING")),
+    }
+
+    let Property(name, params, value) = property;
+    match value {
+        PropertyValue::XType(s) if is_binary => nom::combinator::all_consuming(binary)
+            .parse(s.as_str())
+            .map(|(_, binary)| Property(name.clone(), params, PropertyValue::Binary(binary)))
+            .map_err(|_| ValidationError::InvalidPropertyValue(name)),
+        value => Ok(Property(name, params, value)),
+    }
+}
+
+/// Types the URL, TZURL and ATTACH (unless `VALUE=BINARY`) values not typed
+/// yet as URIs, and checks the parameters whose values are URIs.
+pub(crate) fn decode_uri(property: Property) -> Result<Property, ValidationError> {
+    for (name, single) in [
+        ("ALTREP", true),
+        ("DELEGATED-FROM", false),
+        ("DELEGATED-TO", false),
+        ("DIR", true),
+        ("MEMBER", false),
+        ("SENT-BY", true),
+    ] {
+        if let Some(value) = property.parameter(name)
+            && (value.uris().is_none() || (single && value.values().len() != 1))
+        {
+            return Err(ValidationError::InvalidParameterValue(
+                property.0.clone(),
+                ParameterName::new(name),
+            ));
+        }
+    }
+
+    let is_uri = match property.name().as_str() {
+        "URL" | "TZURL" => true,
+        "ATTACH" => property
+            .parameter("VALUE")
+            .is_none_or(|value| matches!(value.values(), [value_type] if value_type.eq_ignore_ascii_case("URI"))),
+        _ => false,
+    };
+    let Property(name, params, value) = property;
+    match value {
+        PropertyValue::XType(s) if is_uri => nom::combinator::all_consuming(uri)
+            .parse(s.as_str())
+            .map(|(_, uri)| Property(name.clone(), params, PropertyValue::Uri(uri)))
+            .map_err(|_| ValidationError::InvalidPropertyValue(name)),
+        value => Ok(Property(name, params, value)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::parser::{contentline, unfold};
+
+    use super::{Component, ComponentName, Property, PropertyName, PropertyValue, Text};
+
+    /// Returns a property whose value is not typed yet.
+    pub(crate) fn property(name: &str, value: &str) -> Property {
+        Property(
+            PropertyName::new(name),
+            Default::default(),
+            PropertyValue::XType(value.to_owned()),
+        )
+    }
+
+    /// Returns a property with a TEXT value.
+    pub(crate) fn text_property(name: &str, value: &str) -> Property {
+        Property(
+            PropertyName::new(name),
+            Default::default(),
+            PropertyValue::Text(Text(value.to_owned())),
+        )
+    }
+
+    pub(crate) fn component(
+        name: &str,
+        properties: Vec<Property>,
+        components: Vec<Component>,
+    ) -> Component {
+        Component(ComponentName::new(name), properties, components)
+    }
+
+    /// Reads a single content line into a property whose value is not typed
+    /// yet.
+    pub(crate) fn parse(input: &str) -> Property {
+        let lines = unfold(input).collect::<Vec<_>>();
+        let [line] = lines.as_slice() else {
+            panic!("not a single content line: {input:?}");
+        };
+        let Ok((_, (name, params, value))) = contentline(line.as_str()) else {
+            panic!("invalid content line: {input:?}");
+        };
+        Property::new(name, params, PropertyValue::XType(value))
+    }
+}
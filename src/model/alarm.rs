@@ -0,0 +1,718 @@
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{
+        Component, ComponentName, DateTime, Duration, Property, PropertyName, PropertyValue,
+        decode_attach, decode_number, decode_text, decode_uri, filter, find, is_x_name, optional,
+        required,
+    },
+    parser::{date_time, dur_value},
+};
+
+/// VALARM
+///
+/// alarmc     = "BEGIN" ":" "VALARM" CRLF
+///              (audioprop / dispprop / emailprop)
+///              "END" ":" "VALARM" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.6>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alarm(
+    pub(crate) AlarmAction,
+    pub(crate) Trigger,
+    pub(crate) Vec<Property>,
+);
+
+impl Alarm {
+    pub fn action(&self) -> &AlarmAction {
+        &self.0
+    }
+
+    pub fn trigger(&self) -> &Trigger {
+        &self.1
+    }
+
+    pub fn properties(&self) -> &[Property] {
+        &self.2
+    }
+
+    pub fn description(&self) -> Option<&Property> {
+        find(&self.2, "DESCRIPTION")
+    }
+
+    pub fn summary(&self) -> Option<&Property> {
+        find(&self.2, "SUMMARY")
+    }
+
+    pub fn duration(&self) -> Option<&Property> {
+        find(&self.2, "DURATION")
+    }
+
+    pub fn repeat(&self) -> Option<&Property> {
+        find(&self.2, "REPEAT")
+    }
+
+    pub fn attachments(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "ATTACH")
+    }
+
+    pub fn attendees(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "ATTENDEE")
+    }
+}
+
+impl TryFrom<&Component> for Alarm {
+    type Error = ValidationError;
+
+    /// audioprop  = *(
+    ///            ;
+    ///            ; 'action' and 'trigger' are both REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            action / trigger /
+    ///            ;
+    ///            ; 'duration' and 'repeat' are both OPTIONAL,
+    ///            ; and MUST NOT occur more than once each;
+    ///            ; but if one occurs, so MUST the other.
+    ///            ;
+    ///            duration / repeat /
+    ///            ;
+    ///            ; The following is OPTIONAL,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            attach /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            x-prop / iana-prop
+    ///            ;
+    ///            )
+    ///
+    /// dispprop   = *(
+    ///            ;
+    ///            ; The following are REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            action / description / trigger /
+    ///            ;
+    ///            ; 'duration' and 'repeat' are both OPTIONAL,
+    ///            ; and MUST NOT occur more than once each;
+    ///            ; but if one occurs, so MUST the other.
+    ///            ;
+    ///            duration / repeat /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            x-prop / iana-prop
+    ///            ;
+    ///            )
+    ///
+    /// emailprop  = *(
+    ///            ;
+    ///            ; The following are all REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            action / description / trigger / summary /
+    ///            ;
+    ///            ; The following is REQUIRED,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attendee /
+    ///            ;
+    ///            ; 'duration' and 'repeat' are both OPTIONAL,
+    ///            ; and MUST NOT occur more than once each;
+    ///            ; but if one occurs, so MUST the other.
+    ///            ;
+    ///            duration / repeat /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attach / x-prop / iana-prop
+    ///            ;
+    ///            )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VALARM" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let action = required(properties, "ACTION")?;
+        let trigger = required(properties, "TRIGGER")?;
+        optional(properties, "DURATION")?;
+        optional(properties, "REPEAT")?;
+        match (find(properties, "DURATION"), find(properties, "REPEAT")) {
+            (Some(duration), None) => {
+                return Err(ValidationError::PropertyRequires(
+                    duration.0.clone(),
+                    PropertyName::new("REPEAT"),
+                ));
+            }
+            (None, Some(repeat)) => {
+                return Err(ValidationError::PropertyRequires(
+                    repeat.0.clone(),
+                    PropertyName::new("DURATION"),
+                ));
+            }
+            _ => {}
+        }
+
+        let action = action
+            .value()
+            .as_str()
+            .map(AlarmAction::from)
+            .ok_or_else(|| ValidationError::InvalidPropertyValue(action.0.clone()))?;
+        match action {
+            AlarmAction::Audio => {
+                optional(properties, "ATTACH")?;
+            }
+            AlarmAction::Display => {
+                required(properties, "DESCRIPTION")?;
+            }
+            AlarmAction::Email => {
+                required(properties, "DESCRIPTION")?;
+                required(properties, "SUMMARY")?;
+                if find(properties, "ATTENDEE").is_none() {
+                    return Err(ValidationError::MissingProperty(PropertyName::new(
+                        "ATTENDEE",
+                    )));
+                }
+            }
+            AlarmAction::XName(_) | AlarmAction::IanaToken(_) => {}
+        }
+
+        let trigger = Trigger::try_from(trigger)?;
+
+        if let Some(component) = component.components().first() {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| {
+                decode_number(property)
+                    .and_then(decode_text)
+                    .and_then(decode_attach)
+                    .and_then(decode_uri)
+            })
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(action, trigger, properties))
+    }
+}
+
+impl From<Alarm> for Component {
+    fn from(alarm: Alarm) -> Self {
+        Component(ComponentName::new("VALARM"), alarm.2, vec![])
+    }
+}
+
+/// actionvalue = "AUDIO" / "DISPLAY" / "EMAIL"
+///             / iana-token / x-name
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.6.1>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AlarmAction {
+    Audio,
+    Display,
+    Email,
+    XName(String),
+    IanaToken(String),
+}
+
+impl From<&str> for AlarmAction {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "AUDIO" => AlarmAction::Audio,
+            "DISPLAY" => AlarmAction::Display,
+            "EMAIL" => AlarmAction::Email,
+            _ if is_x_name(s) => AlarmAction::XName(s.to_owned()),
+            _ => AlarmAction::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+/// TRIGGER
+///
+/// trigger    = "TRIGGER" (trigrel / trigabs) CRLF
+///
+/// trigrel    = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///              (";" "VALUE" "=" "DURATION") /
+///              (";" trigrelparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///              (";" other-param)
+///            ;
+///              ) ":"  dur-value
+///
+/// trigabs    = *(
+///            ;
+///            ; The following is REQUIRED,
+///            ; but MUST NOT occur more than once.
+///            ;
+///              (";" "VALUE" "=" "DATE-TIME") /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///              (";" other-param)
+///            ;
+///              ) ":" date-time
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.6.3>
+#[derive(Clone, Debug, PartialEq)]
+pub enum Trigger {
+    /// A duration relative to the start or the end of the event or to-do.
+    Relative(Duration, Related),
+    /// An absolute UTC DATE-TIME.
+    Absolute(DateTime),
+}
+
+impl TryFrom<&Property> for Trigger {
+    type Error = ValidationError;
+
+    /// Reads a DURATION or a UTC DATE-TIME value, typed or not typed yet.
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let invalid = || ValidationError::InvalidPropertyValue(property.0.clone());
+        let value_type = match property.parameter("VALUE").map(|v| v.values()) {
+            None => "DURATION",
+            Some([value_type]) => &value_type.to_ascii_uppercase(),
+            Some(_) => return Err(invalid()),
+        };
+        match value_type {
+            "DURATION" => {
+                let related = match property.parameter("RELATED").map(|v| v.values()) {
+                    None => Related::Start,
+                    Some([related]) if related.eq_ignore_ascii_case("START") => Related::Start,
+                    Some([related]) if related.eq_ignore_ascii_case("END") => Related::End,
+                    Some(_) => return Err(invalid()),
+                };
+                let duration = match property.value() {
+                    PropertyValue::Duration(duration) => *duration,
+                    PropertyValue::XType(value) => {
+                        let (_, duration) = nom::combinator::all_consuming(dur_value)
+                            .parse(value.as_str())
+                            .map_err(|_| invalid())?;
+                        duration
+                    }
+                    _ => return Err(invalid()),
+                };
+                Ok(Trigger::Relative(duration, related))
+            }
+            "DATE-TIME" if property.parameter("RELATED").is_none() => {
+                let date_time = match property.value() {
+                    PropertyValue::DateTime(date_time) => date_time.clone(),
+                    PropertyValue::XType(value) => {
+                        let (_, date_time) = nom::combinator::all_consuming(date_time)
+                            .parse(value.as_str())
+                            .map_err(|_| invalid())?;
+                        date_time
+                    }
+                    _ => return Err(invalid()),
+                };
+                if !date_time.is_utc() {
+                    return Err(invalid());
+                }
+                Ok(Trigger::Absolute(date_time))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// trigrelparam       = "RELATED" "=" ("START" / "END")
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.14>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Related {
+    Start,
+    End,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, ParameterName, ParameterValue, Text, Time, TimeForm, Uri,
+        fixtures::{component, property},
+    };
+
+    use super::*;
+
+    fn property_with(name: &str, params: &[(&str, &str)], value: &str) -> Property {
+        Property(
+            PropertyName::new(name),
+            params
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        ParameterName::new(name),
+                        ParameterValue(vec![(*value).to_owned()]),
+                    )
+                })
+                .collect(),
+            PropertyValue::XType(value.to_owned()),
+        )
+    }
+
+    #[test]
+    fn test_audio() {
+        let c = component(
+            "VALARM",
+            vec![
+                property_with("TRIGGER", &[("VALUE", "DATE-TIME")], "19970317T133000Z"),
+                property("REPEAT", "4"),
+                property("DURATION", "PT15M"),
+                property("ACTION", "AUDIO"),
+                property_with(
+                    "ATTACH",
+                    &[("FMTTYPE", "audio/basic")],
+                    "ftp://example.com/pub/sounds/bell-01.aud",
+                ),
+            ],
+            vec![],
+        );
+        let Ok(alarm) = Alarm::try_from(&c) else {
+            panic!("VALARM is invalid");
+        };
+        assert_eq!(alarm.action(), &AlarmAction::Audio);
+        assert_eq!(
+            alarm.trigger(),
+            &Trigger::Absolute(DateTime(Date(1997, 3, 17), Time(13, 30, 0, TimeForm::Utc)))
+        );
+        assert_eq!(
+            alarm.attachments().map(Property::value).collect::<Vec<_>>(),
+            Uri::new("ftp://example.com/pub/sounds/bell-01.aud")
+                .map(PropertyValue::Uri)
+                .iter()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Alarm::try_from(&Component::from(alarm.clone())), Ok(alarm));
+
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("ACTION", "AUDIO"),
+                property("ATTACH", "ftp://example.com/a.aud"),
+                property("ATTACH", "ftp://example.com/b.aud"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::DuplicateProperty(PropertyName::new(
+                "ATTACH"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("REPEAT", "2"),
+                property("DURATION", "PT15M"),
+                property("ACTION", "DISPLAY"),
+                property(
+                    "DESCRIPTION",
+                    "Breakfast meeting with executive\\nteam at 8:00 AM EST.",
+                ),
+            ],
+            vec![],
+        );
+        let Ok(alarm) = Alarm::try_from(&c) else {
+            panic!("VALARM is invalid");
+        };
+        assert_eq!(alarm.action(), &AlarmAction::Display);
+        assert_eq!(
+            alarm.trigger(),
+            &Trigger::Relative(Duration(0, -30 * 60), Related::Start)
+        );
+        assert_eq!(
+            alarm.description().map(Property::value),
+            Some(&PropertyValue::Text(Text(
+                "Breakfast meeting with executive\nteam at 8:00 AM EST.".to_owned()
+            )))
+        );
+        assert_eq!(
+            alarm.repeat().map(Property::value),
+            Some(&PropertyValue::Integer(2))
+        );
+        assert_eq!(Alarm::try_from(&Component::from(alarm.clone())), Ok(alarm));
+
+        let c = component(
+            "VALARM",
+            vec![property("TRIGGER", "-PT30M"), property("ACTION", "DISPLAY")],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::MissingProperty(PropertyName::new(
+                "DESCRIPTION"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_invalid_repeat() {
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("REPEAT", "twice"),
+                property("DURATION", "PT15M"),
+                property("ACTION", "DISPLAY"),
+                property("DESCRIPTION", "Reminder"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "REPEAT"
+            )))
+        );
+
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("REPEAT", "-1"),
+                property("DURATION", "PT15M"),
+                property("ACTION", "DISPLAY"),
+                property("DESCRIPTION", "Reminder"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::PropertyValueOutOfRange(PropertyName::new(
+                "REPEAT"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_email() {
+        let c = component(
+            "VALARM",
+            vec![
+                property_with("TRIGGER", &[("RELATED", "END")], "-P2D"),
+                property("ACTION", "EMAIL"),
+                property("ATTENDEE", "mailto:john_doe@example.com"),
+                property(
+                    "SUMMARY",
+                    "*** REMINDER: SEND AGENDA FOR WEEKLY STAFF MEETING ***",
+                ),
+                property("DESCRIPTION", "A draft agenda needs to be sent out."),
+            ],
+            vec![],
+        );
+        let Ok(alarm) = Alarm::try_from(&c) else {
+            panic!("VALARM is invalid");
+        };
+        assert_eq!(alarm.action(), &AlarmAction::Email);
+        assert_eq!(
+            alarm.trigger(),
+            &Trigger::Relative(Duration(-2, 0), Related::End)
+        );
+        assert_eq!(alarm.attendees().count(), 1);
+
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-P2D"),
+                property("ACTION", "EMAIL"),
+                property("SUMMARY", "REMINDER"),
+                property("DESCRIPTION", "A draft agenda needs to be sent out."),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::MissingProperty(PropertyName::new(
+                "ATTENDEE"
+            )))
+        );
+
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-P2D"),
+                property("ACTION", "EMAIL"),
+                property("ATTENDEE", "mailto:john_doe@example.com"),
+                property("DESCRIPTION", "A draft agenda needs to be sent out."),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::MissingProperty(PropertyName::new(
+                "SUMMARY"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_repeat_and_duration() {
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("REPEAT", "2"),
+                property("ACTION", "AUDIO"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::PropertyRequires(
+                PropertyName::new("REPEAT"),
+                PropertyName::new("DURATION")
+            ))
+        );
+
+        let c = component(
+            "VALARM",
+            vec![
+                property("TRIGGER", "-PT30M"),
+                property("DURATION", "PT15M"),
+                property("ACTION", "AUDIO"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Alarm::try_from(&c),
+            Err(ValidationError::PropertyRequires(
+                PropertyName::new("DURATION"),
+                PropertyName::new("REPEAT")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_alarm_action() {
+        assert_eq!(AlarmAction::from("display"), AlarmAction::Display);
+        assert_eq!(
+            AlarmAction::from("x-Sms"),
+            AlarmAction::XName("x-Sms".to_owned())
+        );
+        assert_eq!(
+            AlarmAction::from("Procedure"),
+            AlarmAction::IanaToken("Procedure".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_trigger() {
+        assert_eq!(
+            Trigger::try_from(&property("TRIGGER", "PT15M")),
+            Ok(Trigger::Relative(Duration(0, 15 * 60), Related::Start))
+        );
+        assert_eq!(
+            Trigger::try_from(&property_with(
+                "TRIGGER",
+                &[("VALUE", "DURATION"), ("RELATED", "END")],
+                "PT5M"
+            )),
+            Ok(Trigger::Relative(Duration(0, 5 * 60), Related::End))
+        );
+        assert_eq!(
+            Trigger::try_from(&property_with(
+                "TRIGGER",
+                &[("VALUE", "DATE-TIME")],
+                "19980101T050000Z"
+            )),
+            Ok(Trigger::Absolute(DateTime(
+                Date(1998, 1, 1),
+                Time(5, 0, 0, TimeForm::Utc)
+            )))
+        );
+
+        let invalid = Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+            "TRIGGER",
+        )));
+        // not UTC
+        assert_eq!(
+            Trigger::try_from(&property_with(
+                "TRIGGER",
+                &[("VALUE", "DATE-TIME")],
+                "19980101T050000"
+            )),
+            invalid
+        );
+        // DATE-TIME without VALUE=DATE-TIME
+        assert_eq!(
+            Trigger::try_from(&property("TRIGGER", "19980101T050000Z")),
+            invalid
+        );
+        // RELATED with an absolute trigger
+        assert_eq!(
+            Trigger::try_from(&property_with(
+                "TRIGGER",
+                &[("VALUE", "DATE-TIME"), ("RELATED", "END")],
+                "19980101T050000Z"
+            )),
+            invalid
+        );
+        assert_eq!(
+            Trigger::try_from(&property_with("TRIGGER", &[("RELATED", "MIDDLE")], "PT5M")),
+            invalid
+        );
+        assert_eq!(Trigger::try_from(&property("TRIGGER", "PT")), invalid);
+    }
+
+    #[test]
+    fn test_trigger_typed() {
+        let typed = |params: &[(&str, &str)], value: PropertyValue| {
+            let Property(name, params, _) = property_with("TRIGGER", params, "");
+            Property(name, params, value)
+        };
+        assert_eq!(
+            Trigger::try_from(&typed(
+                &[("RELATED", "END")],
+                PropertyValue::Duration(Duration(0, -5 * 60))
+            )),
+            Ok(Trigger::Relative(Duration(0, -5 * 60), Related::End))
+        );
+        let date_time = DateTime(Date(1998, 1, 1), Time(5, 0, 0, TimeForm::Utc));
+        assert_eq!(
+            Trigger::try_from(&typed(
+                &[("VALUE", "DATE-TIME")],
+                PropertyValue::DateTime(date_time.clone())
+            )),
+            Ok(Trigger::Absolute(date_time.clone()))
+        );
+
+        let invalid = Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+            "TRIGGER",
+        )));
+        // a DATE-TIME without VALUE=DATE-TIME
+        assert_eq!(
+            Trigger::try_from(&typed(&[], PropertyValue::DateTime(date_time))),
+            invalid
+        );
+        // not UTC
+        assert_eq!(
+            Trigger::try_from(&typed(
+                &[("VALUE", "DATE-TIME")],
+                PropertyValue::DateTime(DateTime(
+                    Date(1998, 1, 1),
+                    Time(5, 0, 0, TimeForm::Floating)
+                ))
+            )),
+            invalid
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    error::ValidationError,
+    model::{
+        Binary, MediaType, ParameterValue, Property, PropertyValue, Uri, decode_attach, decode_uri,
+    },
+};
+
+/// ATTACH
+///
+/// attach     = "ATTACH" attachparam ( ":" uri ) /
+///              (
+///                ";" "ENCODING" "=" "BASE64"
+///                ";" "VALUE" "=" "BINARY"
+///                ":" binary
+///              )
+///              CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attachment(pub(crate) AttachmentValue, pub(crate) Option<MediaType>);
+
+impl Attachment {
+    pub fn new(value: AttachmentValue, fmttype: Option<MediaType>) -> Self {
+        Self(value, fmttype)
+    }
+
+    /// An attachment of the octets inline, as a BINARY value.
+    pub fn binary(bytes: Vec<u8>, fmttype: Option<MediaType>) -> Self {
+        Self(AttachmentValue::Binary(Binary(bytes)), fmttype)
+    }
+
+    pub fn value(&self) -> &AttachmentValue {
+        &self.0
+    }
+
+    pub fn fmttype(&self) -> Option<&MediaType> {
+        self.1.as_ref()
+    }
+}
+
+/// The value of ATTACH, either a reference to the document or the
+/// document itself.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttachmentValue {
+    Uri(Uri),
+    Binary(Binary),
+}
+
+impl TryFrom<&Property> for Attachment {
+    type Error = ValidationError;
+
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let property = decode_attach(property.clone()).and_then(decode_uri)?;
+        let fmttype = property
+            .parameter("FMTTYPE")
+            .and_then(ParameterValue::media_type);
+        match property.2 {
+            PropertyValue::Uri(uri) => Ok(Self(AttachmentValue::Uri(uri), fmttype)),
+            PropertyValue::Binary(binary) => Ok(Self(AttachmentValue::Binary(binary), fmttype)),
+            _ => Err(ValidationError::InvalidPropertyValue(property.0)),
+        }
+    }
+}
+
+impl From<Attachment> for Property {
+    fn from(attachment: Attachment) -> Self {
+        let mut params = vec![];
+        if let Some(fmttype) = attachment.1 {
+            params.push(("FMTTYPE".to_owned(), vec![fmttype.to_string()]));
+        }
+        let value = match attachment.0 {
+            AttachmentValue::Uri(uri) => PropertyValue::Uri(uri),
+            AttachmentValue::Binary(binary) => {
+                params.push(("ENCODING".to_owned(), vec!["BASE64".to_owned()]));
+                params.push(("VALUE".to_owned(), vec!["BINARY".to_owned()]));
+                PropertyValue::Binary(binary)
+            }
+        };
+        Property::new("ATTACH".to_owned(), params, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ValidationError,
+        model::{ParameterName, PropertyName, fixtures::parse},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let property = parse(
+            "ATTACH;FMTTYPE=text/plain;ENCODING=BASE64;VALUE=BINARY:VGhlIHF1aWNrIGJyb3duIGZveA==\r\n",
+        );
+        assert_eq!(
+            Attachment::try_from(&property),
+            Ok(Attachment(
+                AttachmentValue::Binary(Binary(b"The quick brown fox".to_vec())),
+                Some(MediaType("text".to_owned(), "plain".to_owned()))
+            ))
+        );
+
+        let property = parse("ATTACH:CID:jsmith.part3.960817T083000.xyzMail@example.com\r\n");
+        assert_eq!(
+            Attachment::try_from(&property).ok(),
+            Uri::new("CID:jsmith.part3.960817T083000.xyzMail@example.com")
+                .map(|uri| Attachment(AttachmentValue::Uri(uri), None))
+        );
+    }
+
+    #[test]
+    fn test_try_from_invalid_parameters() {
+        let invalid = |name: &str| {
+            Err(ValidationError::InvalidParameterValue(
+                PropertyName::new("ATTACH"),
+                ParameterName::new(name),
+            ))
+        };
+        let property = parse("ATTACH;VALUE=BINARY:VGhl\r\n");
+        assert_eq!(Attachment::try_from(&property), invalid("ENCODING"));
+        let property = parse("ATTACH;ENCODING=BASE64:VGhl\r\n");
+        assert_eq!(Attachment::try_from(&property), invalid("ENCODING"));
+        let property = parse("ATTACH;ENCODING=8BIT;VALUE=BINARY:VGhl\r\n");
+        assert_eq!(Attachment::try_from(&property), invalid("ENCODING"));
+        let property = parse("ATTACH;VALUE=TEXT:abc\r\n");
+        assert_eq!(Attachment::try_from(&property), invalid("VALUE"));
+        let property = parse("ATTACH;FMTTYPE=text:http://example.com/a.txt\r\n");
+        assert_eq!(Attachment::try_from(&property), invalid("FMTTYPE"));
+
+        let property = parse("ATTACH;ENCODING=BASE64;VALUE=BINARY:VGh\r\n");
+        assert_eq!(
+            Attachment::try_from(&property),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "ATTACH"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_into_property() {
+        let attachment = Attachment::binary(
+            (0..=255).collect(),
+            Some(MediaType(
+                "application".to_owned(),
+                "octet-stream".to_owned(),
+            )),
+        );
+        let s = Property::from(attachment.clone()).to_string();
+        assert!(s.starts_with(
+            "ATTACH;FMTTYPE=application/octet-stream;ENCODING=BASE64;VALUE=BINARY:AAECAw"
+        ));
+        assert!(s.ends_with("+/w==\r\n"));
+        assert!(s.split_terminator("\r\n").all(|line| line.len() <= 75));
+        assert!(
+            s.split_terminator("\r\n")
+                .skip(1)
+                .all(|line| line.starts_with(' '))
+        );
+        assert_eq!(Attachment::try_from(&parse(&s)), Ok(attachment));
+    }
+}
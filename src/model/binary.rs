@@ -0,0 +1,92 @@
+use std::fmt;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// BINARY
+///
+/// binary     = *(4b-char) [b-end]
+/// ; A "BASE64" encoded character string, as defined by [RFC4648].
+///
+/// Holds the decoded octets; the value is encoded again when displayed.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.1>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Binary(pub(crate) Vec<u8>);
+
+impl Binary {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Decodes a string already checked by [`binary`](crate::parser::binary).
+    pub(crate) fn decode(s: &str) -> Self {
+        let mut bytes = Vec::with_capacity(s.len() / 4 * 3);
+        let mut bits = 0u32;
+        let mut count = 0;
+        for c in s.bytes().take_while(|c| *c != b'=') {
+            let sextet = ALPHABET
+                .iter()
+                .position(|a| *a == c)
+                .expect("b-char is in the alphabet");
+            bits = bits << 6 | sextet as u32;
+            count += 6;
+            if count >= 8 {
+                count -= 8;
+                bytes.push((bits >> count) as u8);
+            }
+        }
+        Self(bytes)
+    }
+}
+
+impl fmt::Display for Binary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.0.chunks(3) {
+            let bits = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |bits, (i, b)| bits | u32::from(*b) << (16 - 8 * i));
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let sextet = (bits >> (18 - 6 * i)) & 0x3f;
+                    write!(f, "{}", ALPHABET[sextet as usize] as char)?;
+                } else {
+                    write!(f, "=")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(Binary::decode(""), Binary(vec![]));
+        assert_eq!(Binary::decode("Zm9v"), Binary(b"foo".to_vec()));
+        assert_eq!(Binary::decode("Zm9vYg=="), Binary(b"foob".to_vec()));
+        assert_eq!(Binary::decode("Zm9vYmE="), Binary(b"fooba".to_vec()));
+        assert_eq!(Binary::decode("+/8="), Binary(vec![0xfb, 0xff]));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Binary(vec![]).to_string(), "");
+        assert_eq!(Binary(b"f".to_vec()).to_string(), "Zg==");
+        assert_eq!(Binary(b"fo".to_vec()).to_string(), "Zm8=");
+        assert_eq!(Binary(b"foo".to_vec()).to_string(), "Zm9v");
+        assert_eq!(Binary(b"foobar".to_vec()).to_string(), "Zm9vYmFy");
+        assert_eq!(Binary(vec![0xfb, 0xff]).to_string(), "+/8=");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::model::{DateTime, Property, Text, filter, find};
+
+/// The properties VEVENT, VTODO and VJOURNAL have in common.
+///
+/// DTSTAMP and UID are REQUIRED and typed when the component is read; the
+/// other properties are looked up by name.
+pub trait CommonProperties {
+    fn properties(&self) -> &[Property];
+
+    fn dtstamp(&self) -> &DateTime;
+
+    fn uid(&self) -> &Text;
+
+    fn class(&self) -> Option<&Property> {
+        find(self.properties(), "CLASS")
+    }
+
+    fn created(&self) -> Option<&Property> {
+        find(self.properties(), "CREATED")
+    }
+
+    /// DTSTART may be a DATE value (`VALUE=DATE`).
+    fn dtstart(&self) -> Option<&Property> {
+        find(self.properties(), "DTSTART")
+    }
+
+    fn last_modified(&self) -> Option<&Property> {
+        find(self.properties(), "LAST-MODIFIED")
+    }
+
+    fn organizer(&self) -> Option<&Property> {
+        find(self.properties(), "ORGANIZER")
+    }
+
+    fn recurrence_id(&self) -> Option<&Property> {
+        find(self.properties(), "RECURRENCE-ID")
+    }
+
+    fn sequence(&self) -> Option<&Property> {
+        find(self.properties(), "SEQUENCE")
+    }
+
+    fn summary(&self) -> Option<&Property> {
+        find(self.properties(), "SUMMARY")
+    }
+
+    fn url(&self) -> Option<&Property> {
+        find(self.properties(), "URL")
+    }
+
+    /// RRULE SHOULD NOT occur more than once, but it MAY.
+    fn rrules(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "RRULE")
+    }
+
+    fn attachments(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "ATTACH")
+    }
+
+    fn attendees(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "ATTENDEE")
+    }
+
+    fn categories(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "CATEGORIES")
+    }
+
+    fn comments(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "COMMENT")
+    }
+
+    fn contacts(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "CONTACT")
+    }
+
+    fn exdates(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "EXDATE")
+    }
+
+    fn related_to(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "RELATED-TO")
+    }
+
+    fn rdates(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "RDATE")
+    }
+
+    fn request_statuses(&self) -> impl Iterator<Item = &Property> {
+        filter(self.properties(), "REQUEST-STATUS")
+    }
+}
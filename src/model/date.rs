@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::model::Duration;
+
+/// DATE
+///
+/// date               = date-value
+///
+/// date-value         = date-fullyear date-month date-mday
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.4>
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date(pub(crate) u16, pub(crate) u8, pub(crate) u8);
+
+impl Date {
+    /// Returns `None` unless `year` has 4 digits and `month` and `day`
+    /// name a day of that year.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if year > 9999 || !(1..=12).contains(&month) {
+            return None;
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self(year, month, day))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.0
+    }
+
+    pub fn month(&self) -> u8 {
+        self.1
+    }
+
+    pub fn day(&self) -> u8 {
+        self.2
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        match (self.to_epoch_days() + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Returns the date `days` days later (or earlier, if negative), or
+    /// `None` if it is outside of the years 0000 to 9999.
+    pub fn add_days(&self, days: i64) -> Option<Self> {
+        Self::from_epoch_days(self.to_epoch_days().checked_add(days)?)
+    }
+
+    /// Returns the number of days from `other` to `self`.
+    pub fn days_since(&self, other: &Date) -> i64 {
+        self.to_epoch_days() - other.to_epoch_days()
+    }
+
+    /// Adds a duration of whole days, returning `None` if it has an exact
+    /// part or if the result is out of range.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        if duration.1 != 0 {
+            return None;
+        }
+        self.add_days(duration.0)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.checked_add(-duration)
+    }
+
+    /// Days since 1970-01-01.
+    pub(crate) fn to_epoch_days(self) -> i64 {
+        // <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+        let (year, month, day) = (i64::from(self.0), i64::from(self.1), i64::from(self.2));
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    pub(crate) fn from_epoch_days(days: i64) -> Option<Self> {
+        // <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+        let days = days.checked_add(719468)?;
+        let era = days.div_euclid(146097);
+        let doe = days.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self::new(
+            u16::try_from(year).ok()?,
+            u8::try_from(month).ok()?,
+            u8::try_from(day).ok()?,
+        )
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.0, self.1, self.2)
+    }
+}
+
+/// weekday     = "SU" / "MO" / "TU" / "WE" / "TH" / "FR" / "SA"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "MO",
+            Weekday::Tuesday => "TU",
+            Weekday::Wednesday => "WE",
+            Weekday::Thursday => "TH",
+            Weekday::Friday => "FR",
+            Weekday::Saturday => "SA",
+            Weekday::Sunday => "SU",
+        }
+    }
+}
+
+pub(crate) fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Date::new(1997, 7, 14), Some(Date(1997, 7, 14)));
+        assert_eq!(Date::new(2000, 2, 29), Some(Date(2000, 2, 29)));
+        assert_eq!(Date::new(2024, 2, 29), Some(Date(2024, 2, 29)));
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert_eq!(Date::new(2023, 13, 1), None);
+        assert_eq!(Date::new(2023, 0, 1), None);
+        assert_eq!(Date::new(2023, 1, 32), None);
+        assert_eq!(Date::new(2023, 4, 31), None);
+        assert_eq!(Date::new(2023, 1, 0), None);
+        assert_eq!(Date::new(10000, 1, 1), None);
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(Date(1997, 7, 14) < Date(1997, 7, 15));
+        assert!(Date(1997, 7, 14) < Date(1997, 8, 1));
+        assert!(Date(1997, 12, 31) < Date(1998, 1, 1));
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(Date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(Date(1997, 7, 14).weekday(), Weekday::Monday);
+        assert_eq!(Date(2000, 2, 29).weekday(), Weekday::Tuesday);
+        assert_eq!(Date(1969, 12, 28).weekday(), Weekday::Sunday);
+        assert_eq!(Date(0, 3, 1).weekday(), Weekday::Wednesday);
+    }
+
+    #[test]
+    fn test_add_days() {
+        assert_eq!(Date(1997, 7, 14).add_days(0), Some(Date(1997, 7, 14)));
+        assert_eq!(Date(1997, 7, 14).add_days(18), Some(Date(1997, 8, 1)));
+        assert_eq!(Date(1997, 12, 31).add_days(1), Some(Date(1998, 1, 1)));
+        assert_eq!(Date(2000, 2, 28).add_days(1), Some(Date(2000, 2, 29)));
+        assert_eq!(Date(2023, 2, 28).add_days(1), Some(Date(2023, 3, 1)));
+        assert_eq!(Date(1970, 1, 1).add_days(-1), Some(Date(1969, 12, 31)));
+        assert_eq!(Date(9999, 12, 31).add_days(1), None);
+        assert_eq!(Date(0, 1, 1).add_days(-1), None);
+    }
+
+    #[test]
+    fn test_days_since() {
+        assert_eq!(Date(1998, 1, 1).days_since(&Date(1997, 1, 1)), 365);
+        assert_eq!(Date(2001, 1, 1).days_since(&Date(2000, 1, 1)), 366);
+        assert_eq!(Date(1997, 1, 1).days_since(&Date(1998, 1, 1)), -365);
+        assert_eq!(Date(1970, 1, 1).to_epoch_days(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Date(1997, 7, 14).to_string(), "19970714");
+        assert_eq!(Date(5, 1, 2).to_string(), "00050102");
+    }
+
+    #[test]
+    fn test_date_checked_add() {
+        assert_eq!(
+            Date(1997, 12, 31).checked_add(Duration(1, 0)),
+            Some(Date(1998, 1, 1))
+        );
+        assert_eq!(
+            Date(1998, 1, 1).checked_sub(Duration::weeks(1)),
+            Some(Date(1997, 12, 25))
+        );
+        assert_eq!(Date(1998, 1, 1).checked_add(Duration(0, 3600)), None);
+    }
+}
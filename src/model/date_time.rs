@@ -0,0 +1,316 @@
+use std::{cmp::Ordering, fmt};
+
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{Date, Duration, Property, PropertyValue},
+    parser::date_time,
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// TIME
+///
+/// time         = time-hour time-minute time-second [time-utc]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.12>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Time(
+    pub(crate) u8,
+    pub(crate) u8,
+    pub(crate) u8,
+    pub(crate) TimeForm,
+);
+
+impl Time {
+    /// Returns `None` unless `hour` is 00-23, `minute` 00-59 and
+    /// `second` 00-60 (60 being a positive leap second).
+    pub fn new(hour: u8, minute: u8, second: u8, form: TimeForm) -> Option<Self> {
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+        Some(Self(hour, minute, second, form))
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.0
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.1
+    }
+
+    pub fn second(&self) -> u8 {
+        self.2
+    }
+
+    pub fn form(&self) -> &TimeForm {
+        &self.3
+    }
+
+    /// Refers a local time to the time zone `tzid`.
+    ///
+    /// Returns `None` for a UTC time, which the TZID parameter MUST NOT
+    /// be applied to.
+    pub fn with_tzid(self, tzid: &str) -> Option<Self> {
+        match self.3 {
+            TimeForm::Utc => None,
+            _ => Some(Self(
+                self.0,
+                self.1,
+                self.2,
+                TimeForm::Zoned(tzid.to_owned()),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}{:02}", self.0, self.1, self.2)?;
+        if self.3 == TimeForm::Utc {
+            write!(f, "Z")?;
+        }
+        Ok(())
+    }
+}
+
+/// The three forms of TIME and DATE-TIME values.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TimeForm {
+    /// FORM #1: DATE WITH LOCAL TIME, not bound to any time zone.
+    Floating,
+    /// FORM #2: DATE WITH UTC TIME, with the "Z" suffix.
+    Utc,
+    /// FORM #3: DATE WITH LOCAL TIME AND TIME ZONE REFERENCE, from the
+    /// TZID property parameter.
+    Zoned(String),
+}
+
+/// DATE-TIME
+///
+/// date-time  = date "T" time ;As specified in the DATE and TIME
+///                            ;value definitions
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DateTime(pub(crate) Date, pub(crate) Time);
+
+impl DateTime {
+    pub fn date(&self) -> Date {
+        self.0
+    }
+
+    pub fn time(&self) -> &Time {
+        &self.1
+    }
+
+    pub fn form(&self) -> &TimeForm {
+        self.1.form()
+    }
+
+    pub fn is_utc(&self) -> bool {
+        *self.form() == TimeForm::Utc
+    }
+
+    /// See [`Time::with_tzid`].
+    pub fn with_tzid(self, tzid: &str) -> Option<Self> {
+        Some(Self(self.0, self.1.with_tzid(tzid)?))
+    }
+
+    /// Adds the days of the duration to the date, then its seconds to the
+    /// time, keeping the form of the DATE-TIME.
+    ///
+    /// Local times are added to as wall clock times, without resolving
+    /// their time zone. Returns `None` if the result is out of range.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let date = self.0.add_days(duration.0)?;
+        let Time(hour, minute, second, form) = &self.1;
+        let seconds = i64::from(*hour) * 3600 + i64::from(*minute) * 60 + i64::from(*second);
+        let seconds = seconds.checked_add(duration.1)?;
+        let date = date.add_days(seconds.div_euclid(SECONDS_PER_DAY))?;
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+        Some(Self(
+            date,
+            Time(
+                (seconds / 3600) as u8,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+                form.clone(),
+            ),
+        ))
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.checked_add(-duration)
+    }
+}
+
+/// DATE-TIMEs only compare in the same form, and local times with a time
+/// zone reference only in the same time zone.
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.form() != other.form() {
+            return None;
+        }
+        let Time(hour, minute, second, _) = &self.1;
+        let Time(other_hour, other_minute, other_second, _) = &other.1;
+        Some((self.0, hour, minute, second).cmp(&(other.0, other_hour, other_minute, other_second)))
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.0, self.1)
+    }
+}
+
+impl TryFrom<&Property> for DateTime {
+    type Error = ValidationError;
+
+    /// Reads a DATE-TIME property value, referring it to the time zone of
+    /// the TZID parameter if any.
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let invalid = || ValidationError::InvalidPropertyValue(property.0.clone());
+        let date_time = match property.value() {
+            PropertyValue::DateTime(date_time) => return Ok(date_time.clone()),
+            PropertyValue::XType(value) => {
+                let (_, date_time) = nom::combinator::all_consuming(date_time)
+                    .parse(value)
+                    .map_err(|_| invalid())?;
+                date_time
+            }
+            _ => return Err(invalid()),
+        };
+        match property.parameter("TZID").map(|v| v.values()) {
+            None => Ok(date_time),
+            Some([tzid]) => date_time.with_tzid(tzid).ok_or_else(invalid),
+            Some(_) => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{ParameterName, ParameterValue, PropertyName};
+
+    use super::*;
+
+    fn property(params: &[(&str, &str)], value: &str) -> Property {
+        Property(
+            PropertyName::new("DTSTART"),
+            params
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        ParameterName::new(name),
+                        ParameterValue(vec![(*value).to_owned()]),
+                    )
+                })
+                .collect(),
+            PropertyValue::XType(value.to_owned()),
+        )
+    }
+
+    #[test]
+    fn test_time_new() {
+        assert_eq!(
+            Time::new(23, 59, 60, TimeForm::Utc),
+            Some(Time(23, 59, 60, TimeForm::Utc))
+        );
+        assert_eq!(Time::new(24, 0, 0, TimeForm::Floating), None);
+        assert_eq!(Time::new(0, 60, 0, TimeForm::Floating), None);
+        assert_eq!(Time::new(0, 0, 61, TimeForm::Floating), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let date_time = DateTime(Date(1998, 1, 18), Time(23, 0, 0, TimeForm::Floating));
+        assert_eq!(date_time.to_string(), "19980118T230000");
+        let date_time = DateTime(Date(1998, 1, 19), Time(7, 0, 0, TimeForm::Utc));
+        assert_eq!(date_time.to_string(), "19980119T070000Z");
+        let date_time = DateTime(
+            Date(1998, 1, 19),
+            Time(2, 0, 0, TimeForm::Zoned("America/New_York".to_owned())),
+        );
+        assert_eq!(date_time.to_string(), "19980119T020000");
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            DateTime::try_from(&property(&[], "19980118T230000")),
+            Ok(DateTime(
+                Date(1998, 1, 18),
+                Time(23, 0, 0, TimeForm::Floating)
+            ))
+        );
+        assert_eq!(
+            DateTime::try_from(&property(&[], "19980119T070000Z")),
+            Ok(DateTime(Date(1998, 1, 19), Time(7, 0, 0, TimeForm::Utc)))
+        );
+        assert_eq!(
+            DateTime::try_from(&property(
+                &[("TZID", "America/New_York")],
+                "19980119T020000"
+            )),
+            Ok(DateTime(
+                Date(1998, 1, 19),
+                Time(2, 0, 0, TimeForm::Zoned("America/New_York".to_owned()))
+            ))
+        );
+
+        let invalid = Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+            "DTSTART",
+        )));
+        assert_eq!(
+            DateTime::try_from(&property(
+                &[("TZID", "America/New_York")],
+                "19980119T070000Z"
+            )),
+            invalid
+        );
+        assert_eq!(
+            DateTime::try_from(&property(&[], "19980119T250000")),
+            invalid
+        );
+        assert_eq!(DateTime::try_from(&property(&[], "19980119")), invalid);
+    }
+
+    #[test]
+    fn test_date_time_checked_add() {
+        let date_time = DateTime(Date(1997, 3, 8), Time(16, 0, 0, TimeForm::Utc));
+        assert_eq!(
+            date_time.checked_add(Duration(0, 8 * 3600 + 30 * 60)),
+            Some(DateTime(Date(1997, 3, 9), Time(0, 30, 0, TimeForm::Utc)))
+        );
+        assert_eq!(
+            date_time.checked_add(Duration(1, 3600)),
+            Some(DateTime(Date(1997, 3, 9), Time(17, 0, 0, TimeForm::Utc)))
+        );
+        assert_eq!(
+            date_time.checked_sub(Duration(0, 16 * 3600 + 1)),
+            Some(DateTime(Date(1997, 3, 7), Time(23, 59, 59, TimeForm::Utc)))
+        );
+
+        // One nominal day keeps the local time across a DST change.
+        let tzid = TimeForm::Zoned("America/New_York".to_owned());
+        let date_time = DateTime(Date(2007, 3, 10), Time(12, 0, 0, tzid.clone()));
+        assert_eq!(
+            date_time.checked_add(Duration(1, 0)),
+            Some(DateTime(Date(2007, 3, 11), Time(12, 0, 0, tzid)))
+        );
+
+        let date_time = DateTime(Date(1998, 12, 31), Time(23, 59, 60, TimeForm::Utc));
+        assert_eq!(
+            date_time.checked_add(Duration(0, 0)),
+            Some(DateTime(Date(1999, 1, 1), Time(0, 0, 0, TimeForm::Utc)))
+        );
+
+        let date_time = DateTime(Date(9999, 12, 31), Time(23, 0, 0, TimeForm::Floating));
+        assert_eq!(date_time.checked_add(Duration(0, 3600)), None);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{fmt, ops::Neg};
+
+/// DURATION
+///
+/// dur-value  = (["+"] / "-") "P" (dur-date / dur-time / dur-week)
+///
+/// A duration has a nominal part, in days (a week being 7 days), and an
+/// exact part, in seconds. Days are added as calendar days, whatever
+/// their length, and seconds as exact time.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Duration(pub(crate) i64, pub(crate) i64);
+
+impl Duration {
+    /// Returns `None` if `days` and `seconds` have opposite signs, as a
+    /// duration has a single sign.
+    pub fn new(days: i64, seconds: i64) -> Option<Self> {
+        if days.signum() * seconds.signum() < 0 {
+            return None;
+        }
+        Some(Self(days, seconds))
+    }
+
+    pub fn weeks(weeks: i64) -> Self {
+        Self(weeks * 7, 0)
+    }
+
+    pub fn days(&self) -> i64 {
+        self.0
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.1
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0 || self.1 < 0
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        let (days, seconds) = (self.0.unsigned_abs(), self.1.unsigned_abs());
+        if days == 0 && seconds == 0 {
+            return write!(f, "T0S");
+        }
+        if seconds == 0 && days % 7 == 0 {
+            return write!(f, "{}W", days / 7);
+        }
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+        if seconds != 0 {
+            let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+            write!(f, "T")?;
+            if hours != 0 {
+                write!(f, "{}H", hours)?;
+            }
+            // dur-second can only follow dur-minute.
+            if minutes != 0 || (hours != 0 && seconds != 0) {
+                write!(f, "{}M", minutes)?;
+            }
+            if seconds != 0 {
+                write!(f, "{}S", seconds)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Duration::new(1, 3600), Some(Duration(1, 3600)));
+        assert_eq!(Duration::new(-1, 0), Some(Duration(-1, 0)));
+        assert_eq!(Duration::new(0, -60), Some(Duration(0, -60)));
+        assert_eq!(Duration::new(-1, 60), None);
+        assert_eq!(Duration::weeks(2), Duration(14, 0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Duration(15, 5 * 3600 + 20).to_string(), "P15DT5H0M20S");
+        assert_eq!(Duration(49, 0).to_string(), "P7W");
+        assert_eq!(Duration(0, 15 * 60).to_string(), "PT15M");
+        assert_eq!(Duration(-1, 0).to_string(), "-P1D");
+        assert_eq!(Duration(0, -30 * 60).to_string(), "-PT30M");
+        assert_eq!(Duration(0, 3600).to_string(), "PT1H");
+        assert_eq!(Duration(0, 0).to_string(), "PT0S");
+    }
+}
@@ -0,0 +1,656 @@
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{
+        Alarm, CommonProperties, Component, ComponentName, DateTime, Property, PropertyValue, Text,
+        decode_attach, decode_number, decode_text, decode_uri, exclusive, filter, find, optional,
+        required,
+    },
+    parser::date_value,
+};
+
+/// VEVENT
+///
+/// eventc     = "BEGIN" ":" "VEVENT" CRLF
+///              eventprop *alarmc
+///              "END" ":" "VEVENT" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event(
+    pub(crate) DateTime,
+    pub(crate) Text,
+    pub(crate) Vec<Property>,
+    pub(crate) Vec<Alarm>,
+);
+
+impl Event {
+    pub fn description(&self) -> Option<&Property> {
+        find(&self.2, "DESCRIPTION")
+    }
+
+    pub fn geo(&self) -> Option<&Property> {
+        find(&self.2, "GEO")
+    }
+
+    pub fn location(&self) -> Option<&Property> {
+        find(&self.2, "LOCATION")
+    }
+
+    pub fn priority(&self) -> Option<&Property> {
+        find(&self.2, "PRIORITY")
+    }
+
+    pub fn status(&self) -> Option<EventStatus> {
+        find(&self.2, "STATUS").and_then(EventStatus::from_property)
+    }
+
+    pub fn transp(&self) -> Option<&Property> {
+        find(&self.2, "TRANSP")
+    }
+
+    pub fn dtend(&self) -> Option<&Property> {
+        find(&self.2, "DTEND")
+    }
+
+    pub fn duration(&self) -> Option<&Property> {
+        find(&self.2, "DURATION")
+    }
+
+    pub fn resources(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "RESOURCES")
+    }
+
+    pub fn alarms(&self) -> &[Alarm] {
+        &self.3
+    }
+}
+
+impl CommonProperties for Event {
+    fn properties(&self) -> &[Property] {
+        &self.2
+    }
+
+    fn dtstamp(&self) -> &DateTime {
+        &self.0
+    }
+
+    fn uid(&self) -> &Text {
+        &self.1
+    }
+}
+
+impl TryFrom<&Component> for Event {
+    type Error = ValidationError;
+
+    /// eventprop  = *(
+    ///            ;
+    ///            ; The following are REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            dtstamp / uid /
+    ///            ;
+    ///            ; The following is REQUIRED if the component
+    ///            ; appears in an iCalendar object that doesn't
+    ///            ; specify the "METHOD" property; otherwise, it
+    ///            ; is OPTIONAL; in any case, it MUST NOT occur
+    ///            ; more than once.
+    ///            ;
+    ///            dtstart /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            class / created / description / geo /
+    ///            last-mod / location / organizer / priority /
+    ///            seq / status / summary / transp /
+    ///            url / recurid /
+    ///            ;
+    ///            ; The following is OPTIONAL,
+    ///            ; but SHOULD NOT occur more than once.
+    ///            ;
+    ///            rrule /
+    ///            ;
+    ///            ; Either 'dtend' or 'duration' MAY appear in
+    ///            ; a 'eventprop', but 'dtend' and 'duration'
+    ///            ; MUST NOT occur in the same 'eventprop'.
+    ///            ;
+    ///            dtend / duration /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attach / attendee / categories / comment /
+    ///            contact / exdate / rstatus / related /
+    ///            resources / rdate / x-prop / iana-prop
+    ///            ;
+    ///            )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VEVENT" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let dtstamp = DateTime::try_from(required(properties, "DTSTAMP")?)?;
+        let uid = Text::try_from(required(properties, "UID")?)?;
+        for name in [
+            "DTSTART",
+            "CLASS",
+            "CREATED",
+            "DESCRIPTION",
+            "GEO",
+            "LAST-MODIFIED",
+            "LOCATION",
+            "ORGANIZER",
+            "PRIORITY",
+            "SEQUENCE",
+            "STATUS",
+            "SUMMARY",
+            "TRANSP",
+            "URL",
+            "RECURRENCE-ID",
+            "DTEND",
+            "DURATION",
+        ] {
+            optional(properties, name)?;
+        }
+        exclusive(properties, "DTEND", "DURATION")?;
+        if let Some(status) = find(properties, "STATUS") {
+            EventStatus::from_property(status)
+                .ok_or_else(|| ValidationError::InvalidPropertyValue(status.0.clone()))?;
+        }
+
+        let alarms = component
+            .components()
+            .iter()
+            .map(Alarm::try_from)
+            .collect::<Result<Vec<Alarm>, ValidationError>>()?;
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| {
+                decode_date_time(property)
+                    .and_then(decode_number)
+                    .and_then(decode_text)
+                    .and_then(decode_attach)
+                    .and_then(decode_uri)
+            })
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(dtstamp, uid, properties, alarms))
+    }
+}
+
+impl From<Event> for Component {
+    fn from(event: Event) -> Self {
+        Component(
+            ComponentName::new("VEVENT"),
+            event.2,
+            event.3.into_iter().map(Component::from).collect(),
+        )
+    }
+}
+
+/// statvalue-event = "TENTATIVE"    ;Indicates event is tentative.
+///                 / "CONFIRMED"    ;Indicates event is definite.
+///                 / "CANCELLED"    ;Indicates event was cancelled.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.11>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventStatus {
+    Tentative,
+    Confirmed,
+    Cancelled,
+}
+
+impl EventStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventStatus::Tentative => "TENTATIVE",
+            EventStatus::Confirmed => "CONFIRMED",
+            EventStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    fn from_property(property: &Property) -> Option<Self> {
+        match property.value().as_str()?.to_ascii_uppercase().as_str() {
+            "TENTATIVE" => Some(EventStatus::Tentative),
+            "CONFIRMED" => Some(EventStatus::Confirmed),
+            "CANCELLED" => Some(EventStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// Types a DTSTART or DTEND value not typed yet as DATE if it has
+/// `VALUE=DATE`, or as DATE-TIME if it has no VALUE or `VALUE=DATE-TIME`.
+fn decode_date_time(property: Property) -> Result<Property, ValidationError> {
+    if !matches!(property.name().as_str(), "DTSTART" | "DTEND") {
+        return Ok(property);
+    }
+    let PropertyValue::XType(s) = property.value() else {
+        return Ok(property);
+    };
+    let value = match property.parameter("VALUE").map(|value| value.values()) {
+        None => PropertyValue::DateTime(DateTime::try_from(&property)?),
+        Some([value_type]) if value_type.eq_ignore_ascii_case("DATE-TIME") => {
+            PropertyValue::DateTime(DateTime::try_from(&property)?)
+        }
+        Some([value_type]) if value_type.eq_ignore_ascii_case("DATE") => {
+            nom::combinator::all_consuming(date_value)
+                .parse(s.as_str())
+                .map(|(_, date)| PropertyValue::Date(date))
+                .map_err(|_| ValidationError::InvalidPropertyValue(property.0.clone()))?
+        }
+        _ => return Ok(property),
+    };
+    let Property(name, params, _) = property;
+    Ok(Property(name, params, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, Geo, ParameterName, ParameterValue, PropertyName, PropertyValue, Time, TimeForm, Uri,
+        fixtures::{component, property, text_property},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let component = component(
+            "VEVENT",
+            vec![
+                property("UID", "19970901T130000Z-123401@example.com"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("DTSTART", "19970903T163000Z"),
+                property("DTEND", "19970903T190000Z"),
+                property("SUMMARY", "Annual Employee Review"),
+                property("CLASS", "PRIVATE"),
+                property("CATEGORIES", "BUSINESS,HUMAN RESOURCES"),
+                property("ATTENDEE", "mailto:a@example.com"),
+                property("ATTENDEE", "mailto:b@example.com"),
+            ],
+            vec![component(
+                "VALARM",
+                vec![property("TRIGGER", "-PT15M"), property("ACTION", "AUDIO")],
+                vec![],
+            )],
+        );
+        let Ok(event) = Event::try_from(&component) else {
+            panic!("VEVENT is invalid");
+        };
+        assert_eq!(
+            event.uid(),
+            &Text("19970901T130000Z-123401@example.com".to_owned())
+        );
+        assert_eq!(
+            event.dtstamp(),
+            &DateTime(Date(1997, 9, 1), Time(13, 0, 0, TimeForm::Utc))
+        );
+        assert_eq!(
+            event.dtstart().map(Property::value),
+            Some(&PropertyValue::DateTime(DateTime(
+                Date(1997, 9, 3),
+                Time(16, 30, 0, TimeForm::Utc)
+            )))
+        );
+        assert_eq!(
+            event.dtend().map(Property::value),
+            Some(&PropertyValue::DateTime(DateTime(
+                Date(1997, 9, 3),
+                Time(19, 0, 0, TimeForm::Utc)
+            )))
+        );
+        assert_eq!(event.duration(), None);
+        assert_eq!(
+            event.summary(),
+            Some(&text_property("SUMMARY", "Annual Employee Review"))
+        );
+        assert_eq!(event.location(), None);
+        assert_eq!(event.attendees().count(), 2);
+        assert_eq!(event.alarms().len(), 1);
+        assert_eq!(Event::try_from(&Component::from(event.clone())), Ok(event));
+    }
+
+    #[test]
+    fn test_try_from_missing_property() {
+        let component = component("VEVENT", vec![property("UID", "1")], vec![]);
+        assert_eq!(
+            Event::try_from(&component),
+            Err(ValidationError::MissingProperty(PropertyName::new(
+                "DTSTAMP"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_invalid_dtstamp() {
+        let component = component(
+            "VEVENT",
+            vec![property("UID", "1"), property("DTSTAMP", "19970901")],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&component),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "DTSTAMP"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_duplicate_property() {
+        let component = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("SUMMARY", "a"),
+                property("SUMMARY", "b"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&component),
+            Err(ValidationError::DuplicateProperty(PropertyName::new(
+                "SUMMARY"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_dtend_and_duration() {
+        let component = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("DTSTART", "19970903T163000Z"),
+                property("DTEND", "19970903T190000Z"),
+                property("DURATION", "PT1H"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&component),
+            Err(ValidationError::MutuallyExclusiveProperties(
+                PropertyName::new("DTEND"),
+                PropertyName::new("DURATION")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from_dtstart_and_dtend() {
+        let with_params = |name: &str, params: &[(&str, &str)], value: &str| {
+            Property(
+                PropertyName::new(name),
+                params
+                    .iter()
+                    .map(|(name, value)| {
+                        (
+                            ParameterName::new(name),
+                            ParameterValue(vec![(*value).to_owned()]),
+                        )
+                    })
+                    .collect(),
+                PropertyValue::XType(value.to_owned()),
+            )
+        };
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                with_params("DTSTART", &[("VALUE", "DATE")], "19970714"),
+                with_params("DTEND", &[("TZID", "America/New_York")], "19970715T120000"),
+            ],
+            vec![],
+        );
+        let Ok(event) = Event::try_from(&c) else {
+            panic!("VEVENT is invalid");
+        };
+        assert_eq!(
+            event.dtstart().map(Property::value),
+            Some(&PropertyValue::Date(Date(1997, 7, 14)))
+        );
+        assert_eq!(
+            event.dtend().map(Property::value),
+            Some(&PropertyValue::DateTime(DateTime(
+                Date(1997, 7, 15),
+                Time(12, 0, 0, TimeForm::Zoned("America/New_York".to_owned()))
+            )))
+        );
+
+        for dtstart in [
+            property("DTSTART", "19970714"),
+            with_params("DTSTART", &[("VALUE", "DATE")], "19970714T120000"),
+            with_params(
+                "DTSTART",
+                &[("TZID", "America/New_York")],
+                "19970714T120000Z",
+            ),
+        ] {
+            let c = component(
+                "VEVENT",
+                vec![
+                    property("UID", "1"),
+                    property("DTSTAMP", "19970901T130000Z"),
+                    dtstart,
+                ],
+                vec![],
+            );
+            assert_eq!(
+                Event::try_from(&c),
+                Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                    "DTSTART"
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_status() {
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("STATUS", "confirmed"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&c).map(|event| event.status()),
+            Ok(Some(EventStatus::Confirmed))
+        );
+
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("STATUS", "NEEDS-ACTION"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "STATUS"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_unexpected_component() {
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+            ],
+            vec![component("VTODO", vec![], vec![])],
+        );
+        assert_eq!(
+            Event::try_from(&c),
+            Err(ValidationError::UnexpectedComponent(ComponentName::new(
+                "VTODO"
+            )))
+        );
+
+        let c = component("VTODO", vec![], vec![]);
+        assert_eq!(
+            Event::try_from(&c),
+            Err(ValidationError::UnexpectedComponent(ComponentName::new(
+                "VTODO"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_geo() {
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("GEO", "37.386013;-122.082932"),
+            ],
+            vec![],
+        );
+        let Ok(event) = Event::try_from(&c) else {
+            panic!("VEVENT is invalid");
+        };
+        assert_eq!(
+            event.geo().map(Property::value),
+            Some(&PropertyValue::Geo(Geo(37.386013, -122.082932)))
+        );
+
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("GEO", "37.386013;-182.082932"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&c),
+            Err(ValidationError::PropertyValueOutOfRange(PropertyName::new(
+                "GEO"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_uris() {
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("URL", "http://example.com/pub/calendars/jsmith/mytime.ics"),
+            ],
+            vec![],
+        );
+        let Ok(event) = Event::try_from(&c) else {
+            panic!("VEVENT is invalid");
+        };
+        let Some(PropertyValue::Uri(uri)) = event.url().map(Property::value) else {
+            panic!("URL is not a URI");
+        };
+        assert_eq!(uri.scheme(), "http");
+        assert_eq!(uri.authority(), Some("example.com"));
+        assert_eq!(uri.path(), "/pub/calendars/jsmith/mytime.ics");
+
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("URL", "mytime.ics"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Event::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "URL"
+            )))
+        );
+
+        let attendee = |name: &str, values: &[&str]| {
+            Property(
+                PropertyName::new("ATTENDEE"),
+                [(
+                    ParameterName::new(name),
+                    ParameterValue(values.iter().map(|value| (*value).to_owned()).collect()),
+                )]
+                .into_iter()
+                .collect(),
+                PropertyValue::XType("mailto:jdoe@example.com".to_owned()),
+            )
+        };
+        for (name, values) in [
+            ("SENT-BY", &["sray@example.com"][..]),
+            (
+                "SENT-BY",
+                &["mailto:a@example.com", "mailto:b@example.com"][..],
+            ),
+            (
+                "DELEGATED-TO",
+                &["mailto:a@example.com", "b@example.com"][..],
+            ),
+        ] {
+            let c = component(
+                "VEVENT",
+                vec![
+                    property("UID", "1"),
+                    property("DTSTAMP", "19970901T130000Z"),
+                    attendee(name, values),
+                ],
+                vec![],
+            );
+            assert_eq!(
+                Event::try_from(&c),
+                Err(ValidationError::InvalidParameterValue(
+                    PropertyName::new("ATTENDEE"),
+                    ParameterName::new(name)
+                ))
+            );
+        }
+
+        let c = component(
+            "VEVENT",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                attendee(
+                    "DELEGATED-TO",
+                    &["mailto:a@example.com", "mailto:b@example.com"],
+                ),
+            ],
+            vec![],
+        );
+        let Ok(event) = Event::try_from(&c) else {
+            panic!("VEVENT is invalid");
+        };
+        let delegated_to = event
+            .attendees()
+            .next()
+            .and_then(|attendee| attendee.parameter("DELEGATED-TO"))
+            .and_then(ParameterValue::uris);
+        assert_eq!(
+            delegated_to,
+            ["mailto:a@example.com", "mailto:b@example.com"]
+                .into_iter()
+                .map(Uri::new)
+                .collect()
+        );
+    }
+}
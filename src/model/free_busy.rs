@@ -0,0 +1,427 @@
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{
+        Component, ComponentName, DateTime, Period, Property, PropertyValue, Text, decode_uri,
+        filter, find, is_x_name, optional, required,
+    },
+    parser::fbvalue,
+};
+
+/// VFREEBUSY
+///
+/// freebusyc  = "BEGIN" ":" "VFREEBUSY" CRLF
+///              fbprop
+///              "END" ":" "VFREEBUSY" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.4>
+#[derive(Clone, Debug, PartialEq)]
+pub struct FreeBusy(
+    pub(crate) DateTime,
+    pub(crate) Text,
+    pub(crate) Vec<Property>,
+    pub(crate) Vec<FreeBusyTime>,
+);
+
+impl FreeBusy {
+    pub fn properties(&self) -> &[Property] {
+        &self.2
+    }
+
+    pub fn dtstamp(&self) -> &DateTime {
+        &self.0
+    }
+
+    pub fn uid(&self) -> &Text {
+        &self.1
+    }
+
+    pub fn contact(&self) -> Option<&Property> {
+        find(&self.2, "CONTACT")
+    }
+
+    pub fn dtstart(&self) -> Option<&Property> {
+        find(&self.2, "DTSTART")
+    }
+
+    pub fn dtend(&self) -> Option<&Property> {
+        find(&self.2, "DTEND")
+    }
+
+    pub fn organizer(&self) -> Option<&Property> {
+        find(&self.2, "ORGANIZER")
+    }
+
+    pub fn url(&self) -> Option<&Property> {
+        find(&self.2, "URL")
+    }
+
+    pub fn attendees(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "ATTENDEE")
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "COMMENT")
+    }
+
+    /// Returns the FREEBUSY properties, typed.
+    pub fn free_busy_times(&self) -> &[FreeBusyTime] {
+        &self.3
+    }
+
+    pub fn request_statuses(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "REQUEST-STATUS")
+    }
+}
+
+impl TryFrom<&Component> for FreeBusy {
+    type Error = ValidationError;
+
+    /// fbprop     = *(
+    ///            ;
+    ///            ; The following are REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            dtstamp / uid /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            contact / dtstart / dtend /
+    ///            organizer / url /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attendee / comment / freebusy / rstatus / x-prop /
+    ///            iana-prop
+    ///            ;
+    ///            )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VFREEBUSY" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let dtstamp = DateTime::try_from(required(properties, "DTSTAMP")?)?;
+        let uid = Text::try_from(required(properties, "UID")?)?;
+        for name in ["CONTACT", "DTSTART", "DTEND", "ORGANIZER", "URL"] {
+            optional(properties, name)?;
+        }
+        if let Some(component) = component.components().first() {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let free_busy_times = filter(properties, "FREEBUSY")
+            .map(FreeBusyTime::try_from)
+            .collect::<Result<Vec<FreeBusyTime>, ValidationError>>()?;
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(decode_uri)
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(dtstamp, uid, properties, free_busy_times))
+    }
+}
+
+impl From<FreeBusy> for Component {
+    fn from(free_busy: FreeBusy) -> Self {
+        Component(ComponentName::new("VFREEBUSY"), free_busy.2, vec![])
+    }
+}
+
+/// FREEBUSY
+///
+/// freebusy   = "FREEBUSY" fbparam ":" fbvalue CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.6>
+#[derive(Clone, Debug, PartialEq)]
+pub struct FreeBusyTime(pub(crate) FreeBusyType, pub(crate) Vec<Period>);
+
+impl FreeBusyTime {
+    pub fn fbtype(&self) -> &FreeBusyType {
+        &self.0
+    }
+
+    pub fn periods(&self) -> &[Period] {
+        &self.1
+    }
+}
+
+impl TryFrom<&Property> for FreeBusyTime {
+    type Error = ValidationError;
+
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let invalid = || ValidationError::InvalidPropertyValue(property.0.clone());
+        let fbtype = match property.parameter("FBTYPE") {
+            Some(value) => match value.values() {
+                [fbtype] => FreeBusyType::from(fbtype.as_str()),
+                _ => return Err(invalid()),
+            },
+            None => FreeBusyType::Busy,
+        };
+        let periods = match property.value() {
+            PropertyValue::List(values) => values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::Period(period) => Some(period.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<Period>>>()
+                .ok_or_else(invalid)?,
+            PropertyValue::XType(value) => {
+                let (_, periods) = nom::combinator::all_consuming(fbvalue)
+                    .parse(value.as_str())
+                    .map_err(|_| invalid())?;
+                periods
+            }
+            _ => return Err(invalid()),
+        };
+        // Time value MUST be in the UTC time format.
+        if !periods
+            .iter()
+            .all(|period| period.start().is_utc() && period.is_valid())
+        {
+            return Err(invalid());
+        }
+        Ok(Self(fbtype, periods))
+    }
+}
+
+/// fbtypeparam        = "FBTYPE" "=" ("FREE" / "BUSY"
+///                    / "BUSY-UNAVAILABLE" / "BUSY-TENTATIVE"
+///                    / x-name
+///          ; Some experimental iCalendar free/busy type.
+///                    / iana-token)
+///          ; Some other IANA-registered iCalendar free/busy type.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.9>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FreeBusyType {
+    Free,
+    Busy,
+    BusyUnavailable,
+    BusyTentative,
+    XName(String),
+    IanaToken(String),
+}
+
+impl FreeBusyType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FreeBusyType::Free => "FREE",
+            FreeBusyType::Busy => "BUSY",
+            FreeBusyType::BusyUnavailable => "BUSY-UNAVAILABLE",
+            FreeBusyType::BusyTentative => "BUSY-TENTATIVE",
+            FreeBusyType::XName(s) | FreeBusyType::IanaToken(s) => s,
+        }
+    }
+}
+
+impl From<&str> for FreeBusyType {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "FREE" => FreeBusyType::Free,
+            "BUSY" => FreeBusyType::Busy,
+            "BUSY-UNAVAILABLE" => FreeBusyType::BusyUnavailable,
+            "BUSY-TENTATIVE" => FreeBusyType::BusyTentative,
+            _ if is_x_name(s) => FreeBusyType::XName(s.to_owned()),
+            _ => FreeBusyType::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, Duration, ParameterName, ParameterValue, PropertyName, Time, TimeForm,
+        fixtures::{component, property},
+    };
+
+    use super::*;
+
+    fn freebusy(fbtype: Option<&str>, value: &str) -> Property {
+        Property(
+            PropertyName::new("FREEBUSY"),
+            fbtype
+                .map(|fbtype| {
+                    (
+                        ParameterName::new("FBTYPE"),
+                        ParameterValue(vec![fbtype.to_owned()]),
+                    )
+                })
+                .into_iter()
+                .collect(),
+            PropertyValue::XType(value.to_owned()),
+        )
+    }
+
+    #[test]
+    fn test_try_from() {
+        let c = component(
+            "VFREEBUSY",
+            vec![
+                property("UID", "19970901T115957Z-76A912@example.com"),
+                property("DTSTAMP", "19970901T120000Z"),
+                property("ORGANIZER", "jsmith@example.com"),
+                property("DTSTART", "19980313T141711Z"),
+                property("DTEND", "19980410T141711Z"),
+                freebusy(None, "19980314T233000Z/19980315T003000Z"),
+                freebusy(
+                    Some("FREE"),
+                    "19980316T153000Z/PT1H,19980316T173000Z/19980316T183000Z",
+                ),
+                freebusy(Some("X-ABC-OOF"), "19980318T030000Z/19980318T040000Z"),
+            ],
+            vec![],
+        );
+        let Ok(free_busy) = FreeBusy::try_from(&c) else {
+            panic!("VFREEBUSY is invalid");
+        };
+        assert_eq!(
+            free_busy.free_busy_times(),
+            &[
+                FreeBusyTime(
+                    FreeBusyType::Busy,
+                    vec![Period::Explicit(
+                        DateTime(Date(1998, 3, 14), Time(23, 30, 0, TimeForm::Utc)),
+                        DateTime(Date(1998, 3, 15), Time(0, 30, 0, TimeForm::Utc))
+                    )]
+                ),
+                FreeBusyTime(
+                    FreeBusyType::Free,
+                    vec![
+                        Period::Start(
+                            DateTime(Date(1998, 3, 16), Time(15, 30, 0, TimeForm::Utc)),
+                            Duration(0, 3600)
+                        ),
+                        Period::Explicit(
+                            DateTime(Date(1998, 3, 16), Time(17, 30, 0, TimeForm::Utc)),
+                            DateTime(Date(1998, 3, 16), Time(18, 30, 0, TimeForm::Utc))
+                        ),
+                    ]
+                ),
+                FreeBusyTime(
+                    FreeBusyType::XName("X-ABC-OOF".to_owned()),
+                    vec![Period::Explicit(
+                        DateTime(Date(1998, 3, 18), Time(3, 0, 0, TimeForm::Utc)),
+                        DateTime(Date(1998, 3, 18), Time(4, 0, 0, TimeForm::Utc))
+                    )]
+                ),
+            ]
+        );
+        assert_eq!(Component::from(free_busy), c);
+    }
+
+    #[test]
+    fn test_try_from_invalid_freebusy() {
+        let c = component(
+            "VFREEBUSY",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T120000Z"),
+                freebusy(Some("BUSY"), "19980314T233000Z"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            FreeBusy::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "FREEBUSY"
+            )))
+        );
+
+        for value in [
+            "19980315T003000Z/19980314T233000Z",
+            "19980314T233000Z/-PT1H",
+            "19980314T233000/PT1H",
+        ] {
+            let c = component(
+                "VFREEBUSY",
+                vec![
+                    property("UID", "1"),
+                    property("DTSTAMP", "19970901T120000Z"),
+                    freebusy(None, value),
+                ],
+                vec![],
+            );
+            assert_eq!(
+                FreeBusy::try_from(&c),
+                Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                    "FREEBUSY"
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_typed_freebusy() {
+        let typed = |values: Vec<PropertyValue>| {
+            Property(
+                PropertyName::new("FREEBUSY"),
+                Default::default(),
+                PropertyValue::List(values),
+            )
+        };
+        let period = |form: TimeForm| {
+            Period::Start(
+                DateTime(Date(1998, 3, 16), Time(15, 30, 0, form)),
+                Duration(0, 3600),
+            )
+        };
+        assert_eq!(
+            FreeBusyTime::try_from(&typed(vec![PropertyValue::Period(period(TimeForm::Utc))])),
+            Ok(FreeBusyTime(
+                FreeBusyType::Busy,
+                vec![period(TimeForm::Utc)]
+            ))
+        );
+
+        let invalid = Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+            "FREEBUSY",
+        )));
+        assert_eq!(
+            FreeBusyTime::try_from(&typed(vec![PropertyValue::Period(period(
+                TimeForm::Floating
+            ))])),
+            invalid
+        );
+        assert_eq!(
+            FreeBusyTime::try_from(&typed(vec![PropertyValue::Text(Text(
+                "19980316T153000Z/PT1H".to_owned()
+            ))])),
+            invalid
+        );
+    }
+
+    #[test]
+    fn test_free_busy_type() {
+        assert_eq!(FreeBusyType::from("FREE"), FreeBusyType::Free);
+        assert_eq!(FreeBusyType::from("BUSY"), FreeBusyType::Busy);
+        assert_eq!(
+            FreeBusyType::from("BUSY-UNAVAILABLE"),
+            FreeBusyType::BusyUnavailable
+        );
+        assert_eq!(
+            FreeBusyType::from("BUSY-TENTATIVE"),
+            FreeBusyType::BusyTentative
+        );
+        assert_eq!(
+            FreeBusyType::from("X-ABC"),
+            FreeBusyType::XName("X-ABC".to_owned())
+        );
+        assert_eq!(
+            FreeBusyType::from("OOF"),
+            FreeBusyType::IanaToken("OOF".to_owned())
+        );
+        assert_eq!(
+            FreeBusyType::from("x-Abc"),
+            FreeBusyType::XName("x-Abc".to_owned())
+        );
+        assert_eq!(
+            FreeBusyType::from("Oof"),
+            FreeBusyType::IanaToken("Oof".to_owned())
+        );
+    }
+}
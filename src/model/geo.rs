@@ -0,0 +1,42 @@
+/// GEO
+///
+/// geo        = "GEO" geoparam ":" geovalue CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.6>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geo(pub(crate) f64, pub(crate) f64);
+
+impl Geo {
+    /// Returns `None` unless `latitude` is within [-90, 90] and
+    /// `longitude` within [-180, 180].
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return None;
+        }
+        Some(Self(latitude, longitude))
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.0
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Geo::new(37.386013, -122.082932),
+            Some(Geo(37.386013, -122.082932))
+        );
+        assert_eq!(Geo::new(-90.0, 180.0), Some(Geo(-90.0, 180.0)));
+        assert_eq!(Geo::new(90.5, 0.0), None);
+        assert_eq!(Geo::new(0.0, -180.5), None);
+    }
+}
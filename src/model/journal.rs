@@ -0,0 +1,322 @@
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{
+        CommonProperties, Component, ComponentName, DateTime, Property, PropertyValue, Text,
+        decode_attach, decode_number, decode_text, decode_uri, filter, find, optional, required,
+    },
+    parser::date_value,
+};
+
+/// VJOURNAL
+///
+/// journalc   = "BEGIN" ":" "VJOURNAL" CRLF
+///              jourprop
+///              "END" ":" "VJOURNAL" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.3>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Journal(
+    pub(crate) DateTime,
+    pub(crate) Text,
+    pub(crate) Vec<Property>,
+);
+
+impl Journal {
+    pub fn status(&self) -> Option<JournalStatus> {
+        find(&self.2, "STATUS").and_then(JournalStatus::from_property)
+    }
+
+    /// DESCRIPTION MAY occur more than once in a VJOURNAL.
+    pub fn descriptions(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "DESCRIPTION")
+    }
+}
+
+impl CommonProperties for Journal {
+    fn properties(&self) -> &[Property] {
+        &self.2
+    }
+
+    fn dtstamp(&self) -> &DateTime {
+        &self.0
+    }
+
+    fn uid(&self) -> &Text {
+        &self.1
+    }
+}
+
+impl TryFrom<&Component> for Journal {
+    type Error = ValidationError;
+
+    /// jourprop   = *(
+    ///            ;
+    ///            ; The following are REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            dtstamp / uid /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            class / created / dtstart /
+    ///            last-mod / organizer / recurid / seq /
+    ///            status / summary / url /
+    ///            ;
+    ///            ; The following is OPTIONAL,
+    ///            ; but SHOULD NOT occur more than once.
+    ///            ;
+    ///            rrule /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attach / attendee / categories / comment /
+    ///            contact / description / exdate / related / rdate /
+    ///            rstatus / x-prop / iana-prop
+    ///            ;
+    ///            )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VJOURNAL" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let dtstamp = DateTime::try_from(required(properties, "DTSTAMP")?)?;
+        let uid = Text::try_from(required(properties, "UID")?)?;
+        for name in [
+            "CLASS",
+            "CREATED",
+            "DTSTART",
+            "LAST-MODIFIED",
+            "ORGANIZER",
+            "RECURRENCE-ID",
+            "SEQUENCE",
+            "STATUS",
+            "SUMMARY",
+            "URL",
+        ] {
+            optional(properties, name)?;
+        }
+        if let Some(status) = find(properties, "STATUS") {
+            JournalStatus::from_property(status)
+                .ok_or_else(|| ValidationError::InvalidPropertyValue(status.0.clone()))?;
+        }
+        if let Some(component) = component.components().first() {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| {
+                decode_date(property)
+                    .and_then(decode_number)
+                    .and_then(decode_text)
+                    .and_then(decode_attach)
+                    .and_then(decode_uri)
+            })
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(dtstamp, uid, properties))
+    }
+}
+
+impl From<Journal> for Component {
+    fn from(journal: Journal) -> Self {
+        Component(ComponentName::new("VJOURNAL"), journal.2, vec![])
+    }
+}
+
+/// statvalue-jour  = "DRAFT"        ;Indicates journal is draft.
+///                 / "FINAL"        ;Indicates journal is final.
+///                 / "CANCELLED"    ;Indicates journal is removed.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.11>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JournalStatus {
+    Draft,
+    Final,
+    Cancelled,
+}
+
+impl JournalStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalStatus::Draft => "DRAFT",
+            JournalStatus::Final => "FINAL",
+            JournalStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    fn from_property(property: &Property) -> Option<Self> {
+        match property.value().as_str()?.to_ascii_uppercase().as_str() {
+            "DRAFT" => Some(JournalStatus::Draft),
+            "FINAL" => Some(JournalStatus::Final),
+            "CANCELLED" => Some(JournalStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// Types a DTSTART value not typed yet as DATE if it has `VALUE=DATE`.
+fn decode_date(property: Property) -> Result<Property, ValidationError> {
+    let is_date = property.name().as_str() == "DTSTART"
+        && property.parameter("VALUE").is_some_and(
+        |value| matches!(value.values(), [value_type] if value_type.eq_ignore_ascii_case("DATE")),
+    );
+    let Property(name, params, value) = property;
+    match value {
+        PropertyValue::XType(s) if is_date => nom::combinator::all_consuming(date_value)
+            .parse(s.as_str())
+            .map(|(_, date)| Property(name.clone(), params, PropertyValue::Date(date)))
+            .map_err(|_| ValidationError::InvalidPropertyValue(name)),
+        value => Ok(Property(name, params, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, ParameterName, ParameterValue, PropertyName,
+        fixtures::{component, property, text_property},
+    };
+
+    use super::*;
+
+    fn dtstart(value: &str) -> Property {
+        Property(
+            PropertyName::new("DTSTART"),
+            [(
+                ParameterName::new("VALUE"),
+                ParameterValue(vec!["DATE".to_owned()]),
+            )]
+            .into_iter()
+            .collect(),
+            PropertyValue::XType(value.to_owned()),
+        )
+    }
+
+    #[test]
+    fn test_try_from() {
+        let c = component(
+            "VJOURNAL",
+            vec![
+                property("UID", "19970901T130000Z-123405@example.com"),
+                property("DTSTAMP", "19970901T130000Z"),
+                dtstart("19970317"),
+                property("SUMMARY", "Staff meeting minutes"),
+                property(
+                    "DESCRIPTION",
+                    "1. Staff meeting: Participants include Joe\\,\\n Lisa\\, and Bob.",
+                ),
+                property("DESCRIPTION", "2. Telephone\\; fax"),
+                property("STATUS", "DRAFT"),
+            ],
+            vec![],
+        );
+        let Ok(journal) = Journal::try_from(&c) else {
+            panic!("VJOURNAL is invalid");
+        };
+        assert_eq!(
+            journal.dtstart(),
+            Some(&Property(
+                PropertyName::new("DTSTART"),
+                [(
+                    ParameterName::new("VALUE"),
+                    ParameterValue(vec!["DATE".to_owned()]),
+                )]
+                .into_iter()
+                .collect(),
+                PropertyValue::Date(Date(1997, 3, 17)),
+            ))
+        );
+        assert_eq!(
+            journal.summary(),
+            Some(&text_property("SUMMARY", "Staff meeting minutes"))
+        );
+        assert_eq!(
+            journal.descriptions().collect::<Vec<&Property>>(),
+            vec![
+                &text_property(
+                    "DESCRIPTION",
+                    "1. Staff meeting: Participants include Joe,\n Lisa, and Bob."
+                ),
+                &text_property("DESCRIPTION", "2. Telephone; fax"),
+            ]
+        );
+        assert_eq!(journal.status(), Some(JournalStatus::Draft));
+    }
+
+    #[test]
+    fn test_try_from_invalid_values() {
+        let c = component(
+            "VJOURNAL",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("STATUS", "COMPLETED"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Journal::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "STATUS"
+            )))
+        );
+
+        let c = component(
+            "VJOURNAL",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                dtstart("19970317T120000"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Journal::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "DTSTART"
+            )))
+        );
+
+        let c = component(
+            "VJOURNAL",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("DESCRIPTION", "a, b"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Journal::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "DESCRIPTION"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_duplicate_property() {
+        let c = component(
+            "VJOURNAL",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "19970901T130000Z"),
+                property("SUMMARY", "a"),
+                property("SUMMARY", "b"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Journal::try_from(&c),
+            Err(ValidationError::DuplicateProperty(PropertyName::new(
+                "SUMMARY"
+            )))
+        );
+    }
+}
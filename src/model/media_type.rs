@@ -0,0 +1,38 @@
+use std::fmt;
+
+use nom::Parser;
+
+use crate::parser::fmttype;
+
+/// The media type of the FMTTYPE parameter.
+///
+/// fmttypeparam = "FMTTYPE" "=" type-name "/" subtype-name
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.8>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MediaType(pub(crate) String, pub(crate) String);
+
+impl MediaType {
+    /// Returns `None` if `s` is not a type name and a subtype name
+    /// separated by "/".
+    pub fn new(s: &str) -> Option<Self> {
+        nom::combinator::all_consuming(fmttype)
+            .parse(s)
+            .ok()
+            .map(|(_, media_type)| media_type)
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.0
+    }
+
+    pub fn subtype_name(&self) -> &str {
+        &self.1
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
@@ -0,0 +1,615 @@
+use nom::Parser;
+
+use crate::{
+    model::{
+        Boolean, CalendarUserAddress, FreeBusyType, MediaType, ParameterName, ParameterValue,
+        Related, Uri, ValueType, is_x_name,
+    },
+    parser::{boolean, cal_address},
+};
+
+/// icalparameter = altrepparam       ; Alternate text representation
+///               / cnparam           ; Common name
+///               / cutypeparam       ; Calendar user type
+///               / delfromparam      ; Delegator
+///               / deltoparam        ; Delegatee
+///               / dirparam          ; Directory entry
+///               / encodingparam     ; Inline encoding
+///               / fmttypeparam      ; Format type
+///               / fbtypeparam       ; Free/busy time type
+///               / languageparam     ; Language for text
+///               / memberparam       ; Group or list membership
+///               / partstatparam     ; Participation status
+///               / rangeparam        ; Recurrence identifier range
+///               / trigrelparam      ; Alarm trigger relationship
+///               / reltypeparam      ; Relationship type
+///               / roleparam         ; Participation role
+///               / rsvpparam         ; RSVP expectation
+///               / sentbyparam       ; Sent by
+///               / tzidparam         ; Reference to time zone object
+///               / valuetypeparam    ; Property value data type
+///               / other-param
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2>
+#[derive(Clone, Debug, PartialEq)]
+pub enum Parameter {
+    AltRep(Uri),
+    Cn(String),
+    CuType(CalendarUserType),
+    DelegatedFrom(Vec<CalendarUserAddress>),
+    DelegatedTo(Vec<CalendarUserAddress>),
+    Dir(Uri),
+    Encoding(Encoding),
+    FmtType(MediaType),
+    FbType(FreeBusyType),
+    Language(String),
+    Member(Vec<CalendarUserAddress>),
+    PartStat(PartStat),
+    Range(Range),
+    Related(Related),
+    RelType(RelationshipType),
+    Role(Role),
+    Rsvp(bool),
+    SentBy(CalendarUserAddress),
+    TzId(String),
+    Value(ValueType),
+    /// other-param, kept as it is.
+    Other(ParameterName, ParameterValue),
+}
+
+impl Parameter {
+    /// Types the parameter. Parameters not defined by RFC 5545 are kept as
+    /// [`Parameter::Other`].
+    ///
+    /// Returns `None` if the value is not allowed for the parameter.
+    pub fn new(name: &ParameterName, value: &ParameterValue) -> Option<Self> {
+        let single = match value.values() {
+            [single] => Some(single.as_str()),
+            _ => None,
+        };
+        Some(match name.as_str() {
+            "ALTREP" => Parameter::AltRep(Uri::new(single?)?),
+            "CN" => Parameter::Cn(single?.to_owned()),
+            "CUTYPE" => Parameter::CuType(CalendarUserType::from(single?)),
+            "DELEGATED-FROM" => Parameter::DelegatedFrom(parse_cal_addresses(value)?),
+            "DELEGATED-TO" => Parameter::DelegatedTo(parse_cal_addresses(value)?),
+            "DIR" => Parameter::Dir(Uri::new(single?)?),
+            "ENCODING" => Parameter::Encoding(Encoding::new(single?)?),
+            "FMTTYPE" => Parameter::FmtType(value.media_type()?),
+            "FBTYPE" => Parameter::FbType(FreeBusyType::from(single?)),
+            "LANGUAGE" => Parameter::Language(single.filter(|s| !s.is_empty())?.to_owned()),
+            "MEMBER" => Parameter::Member(parse_cal_addresses(value)?),
+            "PARTSTAT" => Parameter::PartStat(PartStat::from(single?)),
+            "RANGE" => Parameter::Range(Range::new(single?)?),
+            "RELATED" => Parameter::Related(match single?.to_ascii_uppercase().as_str() {
+                "START" => Related::Start,
+                "END" => Related::End,
+                _ => return None,
+            }),
+            "RELTYPE" => Parameter::RelType(RelationshipType::from(single?)),
+            "ROLE" => Parameter::Role(Role::from(single?)),
+            "RSVP" => {
+                let (_, rsvp) = nom::combinator::all_consuming(boolean)
+                    .parse(single?)
+                    .ok()?;
+                Parameter::Rsvp(rsvp == Boolean::True)
+            }
+            "SENT-BY" => Parameter::SentBy(parse_cal_address(single?)?),
+            "TZID" => Parameter::TzId(single?.to_owned()),
+            "VALUE" => Parameter::Value(ValueType::from(single?)),
+            _ => Parameter::Other(name.clone(), value.clone()),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Parameter::AltRep(_) => "ALTREP",
+            Parameter::Cn(_) => "CN",
+            Parameter::CuType(_) => "CUTYPE",
+            Parameter::DelegatedFrom(_) => "DELEGATED-FROM",
+            Parameter::DelegatedTo(_) => "DELEGATED-TO",
+            Parameter::Dir(_) => "DIR",
+            Parameter::Encoding(_) => "ENCODING",
+            Parameter::FmtType(_) => "FMTTYPE",
+            Parameter::FbType(_) => "FBTYPE",
+            Parameter::Language(_) => "LANGUAGE",
+            Parameter::Member(_) => "MEMBER",
+            Parameter::PartStat(_) => "PARTSTAT",
+            Parameter::Range(_) => "RANGE",
+            Parameter::Related(_) => "RELATED",
+            Parameter::RelType(_) => "RELTYPE",
+            Parameter::Role(_) => "ROLE",
+            Parameter::Rsvp(_) => "RSVP",
+            Parameter::SentBy(_) => "SENT-BY",
+            Parameter::TzId(_) => "TZID",
+            Parameter::Value(_) => "VALUE",
+            Parameter::Other(name, _) => name.as_str(),
+        }
+    }
+
+    /// Returns the values as they appear in a content line, without
+    /// quotes.
+    pub fn values(&self) -> Vec<String> {
+        let addresses = |addresses: &[CalendarUserAddress]| {
+            addresses
+                .iter()
+                .map(|address| address.as_str().to_owned())
+                .collect()
+        };
+        match self {
+            Parameter::AltRep(uri) | Parameter::Dir(uri) => vec![uri.to_string()],
+            Parameter::Cn(s) | Parameter::Language(s) | Parameter::TzId(s) => vec![s.clone()],
+            Parameter::CuType(cutype) => vec![cutype.as_str().to_owned()],
+            Parameter::DelegatedFrom(values)
+            | Parameter::DelegatedTo(values)
+            | Parameter::Member(values) => addresses(values),
+            Parameter::Encoding(encoding) => vec![encoding.as_str().to_owned()],
+            Parameter::FmtType(media_type) => vec![media_type.to_string()],
+            Parameter::FbType(fbtype) => vec![fbtype.as_str().to_owned()],
+            Parameter::PartStat(partstat) => vec![partstat.as_str().to_owned()],
+            Parameter::Range(range) => vec![range.as_str().to_owned()],
+            Parameter::Related(Related::Start) => vec!["START".to_owned()],
+            Parameter::Related(Related::End) => vec!["END".to_owned()],
+            Parameter::RelType(reltype) => vec![reltype.as_str().to_owned()],
+            Parameter::Role(role) => vec![role.as_str().to_owned()],
+            Parameter::Rsvp(rsvp) => vec![if *rsvp { "TRUE" } else { "FALSE" }.to_owned()],
+            Parameter::SentBy(address) => vec![address.as_str().to_owned()],
+            Parameter::Value(value_type) => vec![value_type.as_str().to_owned()],
+            Parameter::Other(_, value) => value.values().to_vec(),
+        }
+    }
+}
+
+impl From<Parameter> for (ParameterName, ParameterValue) {
+    fn from(parameter: Parameter) -> Self {
+        match parameter {
+            Parameter::Other(name, value) => (name, value),
+            parameter => (
+                ParameterName::new(parameter.name()),
+                ParameterValue(parameter.values()),
+            ),
+        }
+    }
+}
+
+fn parse_cal_address(s: &str) -> Option<CalendarUserAddress> {
+    nom::combinator::all_consuming(cal_address)
+        .parse(s)
+        .ok()
+        .map(|(_, address)| address)
+}
+
+fn parse_cal_addresses(value: &ParameterValue) -> Option<Vec<CalendarUserAddress>> {
+    value
+        .values()
+        .iter()
+        .map(|s| parse_cal_address(s))
+        .collect()
+}
+
+/// cutypeparam        = "CUTYPE" "="
+///                    ("INDIVIDUAL"   ; An individual
+///                   / "GROUP"        ; A group of individuals
+///                   / "RESOURCE"     ; A physical resource
+///                   / "ROOM"         ; A room resource
+///                   / "UNKNOWN"      ; Otherwise not known
+///                   / x-name         ; Experimental type
+///                   / iana-token)    ; Other IANA-registered
+///                                    ; type
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.3>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CalendarUserType {
+    Individual,
+    Group,
+    Resource,
+    Room,
+    Unknown,
+    XName(String),
+    IanaToken(String),
+}
+
+impl CalendarUserType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CalendarUserType::Individual => "INDIVIDUAL",
+            CalendarUserType::Group => "GROUP",
+            CalendarUserType::Resource => "RESOURCE",
+            CalendarUserType::Room => "ROOM",
+            CalendarUserType::Unknown => "UNKNOWN",
+            CalendarUserType::XName(s) | CalendarUserType::IanaToken(s) => s,
+        }
+    }
+}
+
+impl From<&str> for CalendarUserType {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "INDIVIDUAL" => CalendarUserType::Individual,
+            "GROUP" => CalendarUserType::Group,
+            "RESOURCE" => CalendarUserType::Resource,
+            "ROOM" => CalendarUserType::Room,
+            "UNKNOWN" => CalendarUserType::Unknown,
+            _ if is_x_name(s) => CalendarUserType::XName(s.to_owned()),
+            _ => CalendarUserType::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+/// encodingparam      = "ENCODING" "="
+///                    ( "8BIT"
+///    ; "8bit" text encoding is defined in [RFC2045]
+///                    / "BASE64"
+///    ; "BASE64" binary encoding format is defined in [RFC4648]
+///                    )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.7>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    EightBit,
+    Base64,
+}
+
+impl Encoding {
+    pub fn new(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "8BIT" => Some(Encoding::EightBit),
+            "BASE64" => Some(Encoding::Base64),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::EightBit => "8BIT",
+            Encoding::Base64 => "BASE64",
+        }
+    }
+}
+
+/// partstatparam    = "PARTSTAT" "="
+///                   (partstat-event
+///                  / partstat-todo
+///                  / partstat-jour)
+///
+/// partstat-event   = ("NEEDS-ACTION"    ; Event needs action
+///                  / "ACCEPTED"         ; Event accepted
+///                  / "DECLINED"         ; Event declined
+///                  / "TENTATIVE"        ; Event tentatively
+///                                       ; accepted
+///                  / "DELEGATED"        ; Event delegated
+///                  / x-name             ; Experimental status
+///                  / iana-token)        ; Other IANA-registered
+///                                       ; status
+///
+/// partstat-todo    = ... / "COMPLETED"  ; To-do completed
+///                  / "IN-PROCESS"       ; To-do in process of
+///                                       ; being completed
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.12>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartStat {
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+    Delegated,
+    Completed,
+    InProcess,
+    XName(String),
+    IanaToken(String),
+}
+
+impl PartStat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PartStat::NeedsAction => "NEEDS-ACTION",
+            PartStat::Accepted => "ACCEPTED",
+            PartStat::Declined => "DECLINED",
+            PartStat::Tentative => "TENTATIVE",
+            PartStat::Delegated => "DELEGATED",
+            PartStat::Completed => "COMPLETED",
+            PartStat::InProcess => "IN-PROCESS",
+            PartStat::XName(s) | PartStat::IanaToken(s) => s,
+        }
+    }
+}
+
+impl From<&str> for PartStat {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "NEEDS-ACTION" => PartStat::NeedsAction,
+            "ACCEPTED" => PartStat::Accepted,
+            "DECLINED" => PartStat::Declined,
+            "TENTATIVE" => PartStat::Tentative,
+            "DELEGATED" => PartStat::Delegated,
+            "COMPLETED" => PartStat::Completed,
+            "IN-PROCESS" => PartStat::InProcess,
+            _ if is_x_name(s) => PartStat::XName(s.to_owned()),
+            _ => PartStat::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+/// rangeparam = "RANGE" "=" "THISANDFUTURE"
+/// ; To specify the instance specified by the recurrence identifier
+/// ; and all subsequent recurrence instances.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.13>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Range {
+    ThisAndFuture,
+}
+
+impl Range {
+    pub fn new(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "THISANDFUTURE" => Some(Range::ThisAndFuture),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Range::ThisAndFuture => "THISANDFUTURE",
+        }
+    }
+}
+
+/// reltypeparam       = "RELTYPE" "="
+///                     ("PARENT"    ; Parent relationship - Default
+///                    / "CHILD"     ; Child relationship
+///                    / "SIBLING"   ; Sibling relationship
+///                    / iana-token  ; Some other IANA-registered
+///                                  ; iCalendar relationship type
+///                    / x-name)     ; A non-standard, experimental
+///                                  ; relationship type
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.15>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RelationshipType {
+    Parent,
+    Child,
+    Sibling,
+    XName(String),
+    IanaToken(String),
+}
+
+impl RelationshipType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RelationshipType::Parent => "PARENT",
+            RelationshipType::Child => "CHILD",
+            RelationshipType::Sibling => "SIBLING",
+            RelationshipType::XName(s) | RelationshipType::IanaToken(s) => s,
+        }
+    }
+}
+
+impl From<&str> for RelationshipType {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "PARENT" => RelationshipType::Parent,
+            "CHILD" => RelationshipType::Child,
+            "SIBLING" => RelationshipType::Sibling,
+            _ if is_x_name(s) => RelationshipType::XName(s.to_owned()),
+            _ => RelationshipType::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+/// roleparam  = "ROLE" "="
+///             ("CHAIR"             ; Indicates chair of the
+///                                  ; calendar entity
+///            / "REQ-PARTICIPANT"   ; Indicates a participant whose
+///                                  ; participation is required
+///            / "OPT-PARTICIPANT"   ; Indicates a participant whose
+///                                  ; participation is optional
+///            / "NON-PARTICIPANT"   ; Indicates a participant who
+///                                  ; is copied for information
+///                                  ; purposes only
+///            / x-name              ; Experimental role
+///            / iana-token)         ; Other IANA role
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.16>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    Chair,
+    ReqParticipant,
+    OptParticipant,
+    NonParticipant,
+    XName(String),
+    IanaToken(String),
+}
+
+impl Role {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Role::Chair => "CHAIR",
+            Role::ReqParticipant => "REQ-PARTICIPANT",
+            Role::OptParticipant => "OPT-PARTICIPANT",
+            Role::NonParticipant => "NON-PARTICIPANT",
+            Role::XName(s) | Role::IanaToken(s) => s,
+        }
+    }
+}
+
+impl From<&str> for Role {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "CHAIR" => Role::Chair,
+            "REQ-PARTICIPANT" => Role::ReqParticipant,
+            "OPT-PARTICIPANT" => Role::OptParticipant,
+            "NON-PARTICIPANT" => Role::NonParticipant,
+            _ if is_x_name(s) => Role::XName(s.to_owned()),
+            _ => Role::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter(name: &str, values: &[&str]) -> Option<Parameter> {
+        Parameter::new(
+            &ParameterName::new(name),
+            &ParameterValue(values.iter().map(|s| (*s).to_owned()).collect()),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            parameter("ALTREP", &["CID:part3.msg.970415T083000@example.com"]),
+            Uri::new("CID:part3.msg.970415T083000@example.com").map(Parameter::AltRep)
+        );
+        assert_eq!(
+            parameter("CN", &["John Smith"]),
+            Some(Parameter::Cn("John Smith".to_owned()))
+        );
+        assert_eq!(
+            parameter("CUTYPE", &["GROUP"]),
+            Some(Parameter::CuType(CalendarUserType::Group))
+        );
+        assert_eq!(
+            parameter(
+                "DELEGATED-TO",
+                &["mailto:jdoe@example.com", "mailto:jqpublic@example.com"]
+            ),
+            Some(Parameter::DelegatedTo(vec![
+                CalendarUserAddress("mailto:jdoe@example.com".to_owned()),
+                CalendarUserAddress("mailto:jqpublic@example.com".to_owned()),
+            ]))
+        );
+        assert_eq!(
+            parameter("ENCODING", &["BASE64"]),
+            Some(Parameter::Encoding(Encoding::Base64))
+        );
+        assert_eq!(
+            parameter("FBTYPE", &["BUSY"]),
+            Some(Parameter::FbType(FreeBusyType::Busy))
+        );
+        assert_eq!(
+            parameter("PARTSTAT", &["X-MAYBE"]),
+            Some(Parameter::PartStat(PartStat::XName("X-MAYBE".to_owned())))
+        );
+        assert_eq!(
+            parameter("RANGE", &["THISANDFUTURE"]),
+            Some(Parameter::Range(Range::ThisAndFuture))
+        );
+        assert_eq!(
+            parameter("RELATED", &["END"]),
+            Some(Parameter::Related(Related::End))
+        );
+        assert_eq!(
+            parameter("RELTYPE", &["SIBLING"]),
+            Some(Parameter::RelType(RelationshipType::Sibling))
+        );
+        assert_eq!(
+            parameter("ROLE", &["CHAIR"]),
+            Some(Parameter::Role(Role::Chair))
+        );
+        assert_eq!(parameter("RSVP", &["TRUE"]), Some(Parameter::Rsvp(true)));
+        assert_eq!(
+            parameter("VALUE", &["DATE"]),
+            Some(Parameter::Value(ValueType::Date))
+        );
+        assert_eq!(
+            parameter("X-ABC", &["a", "b"]),
+            Some(Parameter::Other(
+                ParameterName::new("X-ABC"),
+                ParameterValue(vec!["a".to_owned(), "b".to_owned()])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_new_case_insensitive_values() {
+        assert_eq!(
+            parameter("PARTSTAT", &["accepted"]),
+            Some(Parameter::PartStat(PartStat::Accepted))
+        );
+        assert_eq!(
+            parameter("RELATED", &["End"]),
+            Some(Parameter::Related(Related::End))
+        );
+        assert_eq!(parameter("RSVP", &["true"]), Some(Parameter::Rsvp(true)));
+        assert_eq!(
+            parameter("VALUE", &["date-time"]),
+            Some(Parameter::Value(ValueType::DateTime))
+        );
+    }
+
+    #[test]
+    fn test_new_keeps_the_spelling_of_other_values() {
+        assert_eq!(
+            parameter("CUTYPE", &["x-Abc"]),
+            Some(Parameter::CuType(CalendarUserType::XName(
+                "x-Abc".to_owned()
+            )))
+        );
+        assert_eq!(
+            parameter("FBTYPE", &["Oof"]),
+            Some(Parameter::FbType(FreeBusyType::IanaToken("Oof".to_owned())))
+        );
+        assert_eq!(
+            parameter("PARTSTAT", &["x-maybe"]),
+            Some(Parameter::PartStat(PartStat::XName("x-maybe".to_owned())))
+        );
+        assert_eq!(
+            parameter("RELTYPE", &["Sibling"]),
+            Some(Parameter::RelType(RelationshipType::Sibling))
+        );
+        assert_eq!(
+            parameter("RELTYPE", &["Cousin"]),
+            Some(Parameter::RelType(RelationshipType::IanaToken(
+                "Cousin".to_owned()
+            )))
+        );
+        assert_eq!(
+            parameter("ROLE", &["x-Observer"]),
+            Some(Parameter::Role(Role::XName("x-Observer".to_owned())))
+        );
+    }
+
+    #[test]
+    fn test_new_invalid_values() {
+        assert_eq!(parameter("ALTREP", &["example.com"]), None);
+        assert_eq!(parameter("CN", &["a", "b"]), None);
+        assert_eq!(parameter("ENCODING", &["7BIT"]), None);
+        assert_eq!(parameter("FMTTYPE", &["text"]), None);
+        assert_eq!(parameter("LANGUAGE", &[""]), None);
+        assert_eq!(parameter("MEMBER", &["mailto:a@example.com", "b"]), None);
+        assert_eq!(parameter("RANGE", &["THISANDPRIOR"]), None);
+        assert_eq!(parameter("RELATED", &["MIDDLE"]), None);
+        assert_eq!(parameter("RSVP", &["YES"]), None);
+    }
+
+    #[test]
+    fn test_into_generic() {
+        for (name, values) in [
+            ("CN", &["John Smith"][..]),
+            ("CUTYPE", &["X-ABC"]),
+            ("CUTYPE", &["x-Abc"]),
+            (
+                "DELEGATED-FROM",
+                &["mailto:a@example.com", "mailto:b@example.com"],
+            ),
+            ("FMTTYPE", &["application/msword"]),
+            ("PARTSTAT", &["IN-PROCESS"]),
+            ("RSVP", &["FALSE"]),
+            ("TZID", &["/example.org/America/New_York"]),
+            ("VALUE", &["X-TYPE"]),
+            ("X-ABC", &["a", "b"]),
+            ("ABC", &[""]),
+        ] {
+            let name = ParameterName::new(name);
+            let value = ParameterValue(values.iter().map(|s| (*s).to_owned()).collect());
+            assert_eq!(
+                Parameter::new(&name, &value).map(<(ParameterName, ParameterValue)>::from),
+                Some((name, value))
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::model::{DateTime, Duration};
+
+/// PERIOD
+///
+/// period     = period-explicit / period-start
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Period {
+    /// period-explicit = date-time "/" date-time
+    Explicit(DateTime, DateTime),
+    /// period-start = date-time "/" dur-value
+    Start(DateTime, Duration),
+}
+
+impl Period {
+    pub fn start(&self) -> &DateTime {
+        match self {
+            Period::Explicit(start, _) | Period::Start(start, _) => start,
+        }
+    }
+
+    /// Returns `None` if the end of a period-start is out of range.
+    pub fn end(&self) -> Option<DateTime> {
+        match self {
+            Period::Explicit(_, end) => Some(end.clone()),
+            Period::Start(start, duration) => start.checked_add(*duration),
+        }
+    }
+
+    /// Refers the start, and the end of a period-explicit, to the time zone
+    /// `tzid`, see [`DateTime::with_tzid`].
+    pub fn with_tzid(self, tzid: &str) -> Option<Self> {
+        match self {
+            Period::Explicit(start, end) => Some(Period::Explicit(
+                start.with_tzid(tzid)?,
+                end.with_tzid(tzid)?,
+            )),
+            Period::Start(start, duration) => Some(Period::Start(start.with_tzid(tzid)?, duration)),
+        }
+    }
+
+    /// Turns a period-start into the period-explicit with the same start
+    /// and end.
+    pub fn normalize(&self) -> Option<Period> {
+        Some(Period::Explicit(self.start().clone(), self.end()?))
+    }
+
+    /// Checks that the end is after the start, which also requires both to
+    /// be in the same form.
+    pub fn is_valid(&self) -> bool {
+        self.end()
+            .is_some_and(|end| self.start().partial_cmp(&end) == Some(Ordering::Less))
+    }
+
+    /// Checks whether the two periods share some time, the end of a period
+    /// being excluded from it.
+    ///
+    /// Periods whose DATE-TIMEs are in different forms cannot be compared
+    /// and are never said to overlap.
+    pub fn overlaps(&self, other: &Period) -> bool {
+        let (Some(end), Some(other_end)) = (self.end(), other.end()) else {
+            return false;
+        };
+        self.start() < &other_end && other.start() < &end
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Period::Explicit(start, end) => write!(f, "{}/{}", start, end),
+            Period::Start(start, duration) => write!(f, "{}/{}", start, duration),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, Time, TimeForm};
+
+    use super::*;
+
+    fn utc(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+        DateTime(Date(year, month, day), Time(hour, minute, 0, TimeForm::Utc))
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            Period::Start(utc(1997, 3, 8, 16, 0), Duration(0, 8 * 3600 + 30 * 60)).normalize(),
+            Some(Period::Explicit(
+                utc(1997, 3, 8, 16, 0),
+                utc(1997, 3, 9, 0, 30)
+            ))
+        );
+        assert_eq!(
+            Period::Explicit(utc(1997, 1, 1, 18, 0), utc(1997, 1, 2, 7, 0)).normalize(),
+            Some(Period::Explicit(
+                utc(1997, 1, 1, 18, 0),
+                utc(1997, 1, 2, 7, 0)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Period::Explicit(utc(1997, 1, 1, 18, 0), utc(1997, 1, 2, 7, 0)).is_valid());
+        assert!(!Period::Explicit(utc(1997, 1, 2, 7, 0), utc(1997, 1, 1, 18, 0)).is_valid());
+        assert!(!Period::Explicit(utc(1997, 1, 1, 18, 0), utc(1997, 1, 1, 18, 0)).is_valid());
+        assert!(Period::Start(utc(1997, 1, 1, 18, 0), Duration(0, 3600)).is_valid());
+        assert!(!Period::Start(utc(1997, 1, 1, 18, 0), Duration(0, -3600)).is_valid());
+        assert!(!Period::Start(utc(1997, 1, 1, 18, 0), Duration(0, 0)).is_valid());
+
+        let floating = DateTime(Date(1997, 1, 2), Time(7, 0, 0, TimeForm::Floating));
+        assert!(!Period::Explicit(utc(1997, 1, 1, 18, 0), floating).is_valid());
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let period = Period::Explicit(utc(1997, 3, 8, 16, 0), utc(1997, 3, 8, 19, 0));
+        assert!(period.overlaps(&Period::Start(utc(1997, 3, 8, 18, 0), Duration(0, 3600))));
+        assert!(period.overlaps(&Period::Explicit(
+            utc(1997, 3, 8, 15, 0),
+            utc(1997, 3, 8, 20, 0)
+        )));
+        assert!(!period.overlaps(&Period::Start(utc(1997, 3, 8, 19, 0), Duration(0, 3600))));
+        assert!(!period.overlaps(&Period::Explicit(
+            utc(1997, 3, 8, 14, 0),
+            utc(1997, 3, 8, 16, 0)
+        )));
+
+        let floating = DateTime(Date(1997, 3, 8), Time(17, 0, 0, TimeForm::Floating));
+        assert!(!period.overlaps(&Period::Start(floating, Duration(0, 3600))));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Period::Explicit(utc(1997, 1, 1, 18, 0), utc(1997, 1, 2, 7, 0)).to_string(),
+            "19970101T180000Z/19970102T070000Z"
+        );
+        assert_eq!(
+            Period::Start(utc(1997, 1, 1, 18, 0), Duration(0, 5 * 3600 + 30 * 60)).to_string(),
+            "19970101T180000Z/PT5H30M"
+        );
+    }
+}
@@ -0,0 +1,495 @@
+use crate::{
+    error::ValidationError,
+    model::{
+        Component, ComponentName, DateTime, Property, PropertyValue, Text, TimeForm, UtcOffset,
+        decode_text, decode_uri, filter, find, optional, required,
+    },
+};
+
+/// VTIMEZONE
+///
+/// timezonec  = "BEGIN" ":" "VTIMEZONE" CRLF
+///              *(
+///              ;
+///              ; 'tzid' is REQUIRED, but MUST NOT occur more
+///              ; than once.
+///              ;
+///              tzid /
+///              ;
+///              ; 'last-mod' and 'tzurl' are OPTIONAL,
+///              ; but MUST NOT occur more than once.
+///              ;
+///              last-mod / tzurl /
+///              ;
+///              ; One of 'standardc' or 'daylightc' MUST occur
+///              ; and each MAY occur more than once.
+///              ;
+///              standardc / daylightc /
+///              ;
+///              ; The following are OPTIONAL,
+///              ; and MAY occur more than once.
+///              ;
+///              x-prop / iana-prop
+///              ;
+///              )
+///              "END" ":" "VTIMEZONE" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.5>
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeZone(
+    pub(crate) Text,
+    pub(crate) Vec<Property>,
+    pub(crate) Vec<Observance>,
+);
+
+impl TimeZone {
+    pub fn properties(&self) -> &[Property] {
+        &self.1
+    }
+
+    pub fn tzid(&self) -> &Text {
+        &self.0
+    }
+
+    pub fn last_modified(&self) -> Option<&Property> {
+        find(&self.1, "LAST-MODIFIED")
+    }
+
+    pub fn tzurl(&self) -> Option<&Property> {
+        find(&self.1, "TZURL")
+    }
+
+    pub fn observances(&self) -> &[Observance] {
+        &self.2
+    }
+
+    pub fn standards(&self) -> impl Iterator<Item = &Observance> {
+        self.2
+            .iter()
+            .filter(|o| o.kind() == ObservanceKind::Standard)
+    }
+
+    pub fn daylights(&self) -> impl Iterator<Item = &Observance> {
+        self.2
+            .iter()
+            .filter(|o| o.kind() == ObservanceKind::Daylight)
+    }
+}
+
+impl TryFrom<&Component> for TimeZone {
+    type Error = ValidationError;
+
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VTIMEZONE" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let tzid = Text::try_from(required(properties, "TZID")?)?;
+        optional(properties, "LAST-MODIFIED")?;
+        optional(properties, "TZURL")?;
+
+        let observances = component
+            .components()
+            .iter()
+            .map(Observance::try_from)
+            .collect::<Result<Vec<Observance>, ValidationError>>()?;
+        if observances.is_empty() {
+            return Err(ValidationError::MissingObservance);
+        }
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| decode_text(property).and_then(decode_uri))
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(tzid, properties, observances))
+    }
+}
+
+impl From<TimeZone> for Component {
+    fn from(time_zone: TimeZone) -> Self {
+        Component(
+            ComponentName::new("VTIMEZONE"),
+            time_zone.1,
+            time_zone.2.into_iter().map(Component::from).collect(),
+        )
+    }
+}
+
+/// STANDARD or DAYLIGHT
+///
+/// standardc  = "BEGIN" ":" "STANDARD" CRLF
+///              tzprop
+///              "END" ":" "STANDARD" CRLF
+///
+/// daylightc  = "BEGIN" ":" "DAYLIGHT" CRLF
+///              tzprop
+///              "END" ":" "DAYLIGHT" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.5>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observance(
+    pub(crate) ObservanceKind,
+    pub(crate) DateTime,
+    pub(crate) UtcOffset,
+    pub(crate) UtcOffset,
+    pub(crate) Vec<Property>,
+);
+
+impl Observance {
+    pub fn kind(&self) -> ObservanceKind {
+        self.0
+    }
+
+    pub fn properties(&self) -> &[Property] {
+        &self.4
+    }
+
+    /// The local time the observance starts at, without a time zone
+    /// reference.
+    pub fn dtstart(&self) -> &DateTime {
+        &self.1
+    }
+
+    /// The UTC offset in use in this observance (TZOFFSETTO).
+    pub fn offset_to(&self) -> UtcOffset {
+        self.2
+    }
+
+    /// The UTC offset in use before this observance (TZOFFSETFROM).
+    pub fn offset_from(&self) -> UtcOffset {
+        self.3
+    }
+
+    /// RRULE SHOULD NOT occur more than once, but it MAY.
+    pub fn rrules(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.4, "RRULE")
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.4, "COMMENT")
+    }
+
+    pub fn rdates(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.4, "RDATE")
+    }
+
+    pub fn tznames(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.4, "TZNAME")
+    }
+}
+
+impl TryFrom<&Component> for Observance {
+    type Error = ValidationError;
+
+    /// tzprop     = *(
+    ///              ;
+    ///              ; The following are REQUIRED,
+    ///              ; but MUST NOT occur more than once.
+    ///              ;
+    ///              dtstart / tzoffsetto / tzoffsetfrom /
+    ///              ;
+    ///              ; The following is OPTIONAL,
+    ///              ; but SHOULD NOT occur more than once.
+    ///              ;
+    ///              rrule /
+    ///              ;
+    ///              ; The following are OPTIONAL,
+    ///              ; and MAY occur more than once.
+    ///              ;
+    ///              comment / rdate / tzname / x-prop / iana-prop
+    ///              ;
+    ///              )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        let kind = match component.name().as_str() {
+            "STANDARD" => ObservanceKind::Standard,
+            "DAYLIGHT" => ObservanceKind::Daylight,
+            _ => return Err(ValidationError::UnexpectedComponent(component.0.clone())),
+        };
+
+        let properties = component.properties();
+        // DTSTART MUST be specified as a date with local time
+        let dtstart = required(properties, "DTSTART")?;
+        let dtstart = DateTime::try_from(dtstart)
+            .ok()
+            .filter(|dtstart| *dtstart.form() == TimeForm::Floating)
+            .ok_or_else(|| ValidationError::InvalidPropertyValue(dtstart.0.clone()))?;
+        let offset_to = UtcOffset::try_from(required(properties, "TZOFFSETTO")?)?;
+        let offset_from = UtcOffset::try_from(required(properties, "TZOFFSETFROM")?)?;
+        if let Some(component) = component.components().first() {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| match property.name().as_str() {
+                "TZOFFSETFROM" | "TZOFFSETTO" => decode_utc_offset(property),
+                _ => decode_text(property),
+            })
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(kind, dtstart, offset_to, offset_from, properties))
+    }
+}
+
+impl From<Observance> for Component {
+    fn from(observance: Observance) -> Self {
+        Component(
+            ComponentName::new(observance.0.as_str()),
+            observance.4,
+            vec![],
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ObservanceKind {
+    Standard,
+    Daylight,
+}
+
+impl ObservanceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObservanceKind::Standard => "STANDARD",
+            ObservanceKind::Daylight => "DAYLIGHT",
+        }
+    }
+}
+
+/// Types a value not typed yet as UTC-OFFSET.
+fn decode_utc_offset(property: Property) -> Result<Property, ValidationError> {
+    let offset = UtcOffset::try_from(&property)?;
+    let Property(name, params, _) = property;
+    Ok(Property(name, params, PropertyValue::UtcOffset(offset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, ParameterName, ParameterValue, PropertyName, Time,
+        fixtures::{component, property, text_property},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let c = component(
+            "VTIMEZONE",
+            vec![
+                property("TZID", "America/New_York"),
+                property("LAST-MODIFIED", "20050809T050000Z"),
+            ],
+            vec![
+                component(
+                    "DAYLIGHT",
+                    vec![
+                        property("DTSTART", "19670430T020000"),
+                        property(
+                            "RRULE",
+                            "FREQ=YEARLY;BYMONTH=4;BYDAY=-1SU;UNTIL=19730429T070000Z",
+                        ),
+                        property("TZOFFSETFROM", "-0500"),
+                        property("TZOFFSETTO", "-0400"),
+                        property("TZNAME", "EDT"),
+                    ],
+                    vec![],
+                ),
+                component(
+                    "STANDARD",
+                    vec![
+                        property("DTSTART", "19671029T020000"),
+                        property(
+                            "RRULE",
+                            "FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU;UNTIL=20061029T060000Z",
+                        ),
+                        property("TZOFFSETFROM", "-0400"),
+                        property("TZOFFSETTO", "-0500"),
+                        property("TZNAME", "EST"),
+                    ],
+                    vec![],
+                ),
+            ],
+        );
+        let Ok(time_zone) = TimeZone::try_from(&c) else {
+            panic!("VTIMEZONE is invalid");
+        };
+        assert_eq!(time_zone.tzid(), &Text("America/New_York".to_owned()));
+        assert_eq!(time_zone.tzurl(), None);
+        assert_eq!(time_zone.observances().len(), 2);
+
+        let Some(standard) = time_zone.standards().next() else {
+            panic!("no STANDARD observance");
+        };
+        assert_eq!(standard.kind(), ObservanceKind::Standard);
+        assert_eq!(standard.offset_from(), UtcOffset(-4 * 3600));
+        assert_eq!(standard.offset_to(), UtcOffset(-5 * 3600));
+        assert_eq!(
+            find(standard.properties(), "TZOFFSETTO"),
+            Some(&Property(
+                PropertyName::new("TZOFFSETTO"),
+                Default::default(),
+                PropertyValue::UtcOffset(UtcOffset(-5 * 3600)),
+            ))
+        );
+        assert_eq!(
+            standard.tznames().collect::<Vec<&Property>>(),
+            vec![&text_property("TZNAME", "EST")]
+        );
+
+        let Some(daylight) = time_zone.daylights().next() else {
+            panic!("no DAYLIGHT observance");
+        };
+        assert_eq!(
+            daylight.dtstart(),
+            &DateTime(Date(1967, 4, 30), Time(2, 0, 0, TimeForm::Floating))
+        );
+
+        assert_eq!(
+            TimeZone::try_from(&Component::from(time_zone.clone())),
+            Ok(time_zone)
+        );
+    }
+
+    #[test]
+    fn test_try_from_text() {
+        let c = component(
+            "VTIMEZONE",
+            vec![
+                property("TZID", "Europe/Paris"),
+                property("COMMENT", "Paris\\, France"),
+            ],
+            vec![component(
+                "STANDARD",
+                vec![
+                    property("DTSTART", "19961027T030000"),
+                    property("TZOFFSETFROM", "+0200"),
+                    property("TZOFFSETTO", "+0100"),
+                    property("TZNAME", "CET\\; winter"),
+                    property("COMMENT", "a\\nb"),
+                ],
+                vec![],
+            )],
+        );
+        let Ok(time_zone) = TimeZone::try_from(&c) else {
+            panic!("VTIMEZONE is invalid");
+        };
+        assert_eq!(
+            find(time_zone.properties(), "COMMENT"),
+            Some(&text_property("COMMENT", "Paris, France"))
+        );
+        let observance = &time_zone.observances()[0];
+        assert_eq!(
+            observance.tznames().collect::<Vec<&Property>>(),
+            vec![&text_property("TZNAME", "CET; winter")]
+        );
+        assert_eq!(
+            observance.comments().collect::<Vec<&Property>>(),
+            vec![&text_property("COMMENT", "a\nb")]
+        );
+    }
+
+    #[test]
+    fn test_try_from_dtstart_not_local() {
+        let zoned = Property(
+            PropertyName::new("DTSTART"),
+            [(
+                ParameterName::new("TZID"),
+                ParameterValue(vec!["Europe/Paris".to_owned()]),
+            )]
+            .into_iter()
+            .collect(),
+            PropertyValue::XType("19961027T030000".to_owned()),
+        );
+        for dtstart in [property("DTSTART", "19961027T010000Z"), zoned] {
+            let c = component(
+                "VTIMEZONE",
+                vec![property("TZID", "Europe/Paris")],
+                vec![component(
+                    "STANDARD",
+                    vec![
+                        dtstart,
+                        property("TZOFFSETFROM", "+0200"),
+                        property("TZOFFSETTO", "+0100"),
+                    ],
+                    vec![],
+                )],
+            );
+            assert_eq!(
+                TimeZone::try_from(&c),
+                Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                    "DTSTART"
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_without_observance() {
+        let c = component(
+            "VTIMEZONE",
+            vec![property("TZID", "America/New_York")],
+            vec![],
+        );
+        assert_eq!(
+            TimeZone::try_from(&c),
+            Err(ValidationError::MissingObservance)
+        );
+    }
+
+    #[test]
+    fn test_try_from_invalid_observance() {
+        let c = component(
+            "VTIMEZONE",
+            vec![property("TZID", "America/New_York")],
+            vec![component(
+                "STANDARD",
+                vec![
+                    property("DTSTART", "19671029T020000"),
+                    property("TZOFFSETFROM", "-0400"),
+                ],
+                vec![],
+            )],
+        );
+        assert_eq!(
+            TimeZone::try_from(&c),
+            Err(ValidationError::MissingProperty(PropertyName::new(
+                "TZOFFSETTO"
+            )))
+        );
+
+        let c = component(
+            "VTIMEZONE",
+            vec![property("TZID", "America/New_York")],
+            vec![component(
+                "STANDARD",
+                vec![
+                    property("DTSTART", "19671029T020000"),
+                    property("TZOFFSETFROM", "-0400"),
+                    property("TZOFFSETTO", "-0000"),
+                ],
+                vec![],
+            )],
+        );
+        assert_eq!(
+            TimeZone::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "TZOFFSETTO"
+            )))
+        );
+
+        let c = component(
+            "VTIMEZONE",
+            vec![property("TZID", "America/New_York")],
+            vec![component("VEVENT", vec![], vec![])],
+        );
+        assert_eq!(
+            TimeZone::try_from(&c),
+            Err(ValidationError::UnexpectedComponent(ComponentName::new(
+                "VEVENT"
+            )))
+        );
+    }
+}
@@ -0,0 +1,526 @@
+use crate::{
+    error::ValidationError,
+    model::{
+        Alarm, CommonProperties, Component, ComponentName, DateTime, Property, PropertyName,
+        PropertyValue, Text, decode_attach, decode_number, decode_text, decode_uri, exclusive,
+        filter, find, optional, remove, replace, required,
+    },
+};
+
+/// VTODO
+///
+/// todoc      = "BEGIN" ":" "VTODO" CRLF
+///              todoprop *alarmc
+///              "END" ":" "VTODO" CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.2>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Todo(
+    pub(crate) DateTime,
+    pub(crate) Text,
+    pub(crate) Vec<Property>,
+    pub(crate) Vec<Alarm>,
+);
+
+impl Todo {
+    pub fn completed(&self) -> Option<&Property> {
+        find(&self.2, "COMPLETED")
+    }
+
+    pub fn description(&self) -> Option<&Property> {
+        find(&self.2, "DESCRIPTION")
+    }
+
+    pub fn geo(&self) -> Option<&Property> {
+        find(&self.2, "GEO")
+    }
+
+    pub fn location(&self) -> Option<&Property> {
+        find(&self.2, "LOCATION")
+    }
+
+    pub fn percent_complete(&self) -> Option<&Property> {
+        find(&self.2, "PERCENT-COMPLETE")
+    }
+
+    pub fn priority(&self) -> Option<&Property> {
+        find(&self.2, "PRIORITY")
+    }
+
+    pub fn status(&self) -> Option<TodoStatus> {
+        find(&self.2, "STATUS").and_then(TodoStatus::from_property)
+    }
+
+    pub fn due(&self) -> Option<&Property> {
+        find(&self.2, "DUE")
+    }
+
+    pub fn duration(&self) -> Option<&Property> {
+        find(&self.2, "DURATION")
+    }
+
+    pub fn resources(&self) -> impl Iterator<Item = &Property> {
+        filter(&self.2, "RESOURCES")
+    }
+
+    pub fn alarms(&self) -> &[Alarm] {
+        &self.3
+    }
+
+    /// Marks the to-do as completed at `completed`.
+    ///
+    /// Sets STATUS to COMPLETED, COMPLETED to `completed` and
+    /// PERCENT-COMPLETE to 100. The value of COMPLETED MUST be specified in
+    /// the UTC time format, so any other DATE-TIME is rejected and the
+    /// to-do is left unchanged.
+    ///
+    /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.1>
+    pub fn complete(&mut self, completed: DateTime) -> Result<(), ValidationError> {
+        if !completed.is_utc() {
+            return Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "COMPLETED",
+            )));
+        }
+        replace(&mut self.2, TodoStatus::Completed.into_property());
+        replace(
+            &mut self.2,
+            Property(
+                PropertyName::new("COMPLETED"),
+                Default::default(),
+                PropertyValue::DateTime(completed),
+            ),
+        );
+        replace(
+            &mut self.2,
+            Property(
+                PropertyName::new("PERCENT-COMPLETE"),
+                Default::default(),
+                PropertyValue::Integer(100),
+            ),
+        );
+        Ok(())
+    }
+
+    /// Reopens the to-do.
+    ///
+    /// Sets STATUS to NEEDS-ACTION and removes COMPLETED and
+    /// PERCENT-COMPLETE.
+    pub fn reopen(&mut self) {
+        replace(&mut self.2, TodoStatus::NeedsAction.into_property());
+        remove(&mut self.2, "COMPLETED");
+        remove(&mut self.2, "PERCENT-COMPLETE");
+    }
+}
+
+impl CommonProperties for Todo {
+    fn properties(&self) -> &[Property] {
+        &self.2
+    }
+
+    fn dtstamp(&self) -> &DateTime {
+        &self.0
+    }
+
+    fn uid(&self) -> &Text {
+        &self.1
+    }
+}
+
+impl TryFrom<&Component> for Todo {
+    type Error = ValidationError;
+
+    /// todoprop   = *(
+    ///            ;
+    ///            ; The following are REQUIRED,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            dtstamp / uid /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; but MUST NOT occur more than once.
+    ///            ;
+    ///            class / completed / created / description /
+    ///            dtstart / geo / last-mod / location / organizer /
+    ///            percent / priority / recurid / seq / status /
+    ///            summary / url /
+    ///            ;
+    ///            ; The following is OPTIONAL,
+    ///            ; but SHOULD NOT occur more than once.
+    ///            ;
+    ///            rrule /
+    ///            ;
+    ///            ; Either 'due' or 'duration' MAY appear in
+    ///            ; a 'todoprop', but 'due' and 'duration'
+    ///            ; MUST NOT occur in the same 'todoprop'.
+    ///            ; If 'duration' appear in a 'todoprop',
+    ///            ; then 'dtstart' MUST also appear in
+    ///            ; the same 'todoprop'.
+    ///            ;
+    ///            due / duration /
+    ///            ;
+    ///            ; The following are OPTIONAL,
+    ///            ; and MAY occur more than once.
+    ///            ;
+    ///            attach / attendee / categories / comment / contact /
+    ///            exdate / rstatus / related / resources /
+    ///            rdate / x-prop / iana-prop
+    ///            ;
+    ///            )
+    fn try_from(component: &Component) -> Result<Self, Self::Error> {
+        if component.name().as_str() != "VTODO" {
+            return Err(ValidationError::UnexpectedComponent(component.0.clone()));
+        }
+
+        let properties = component.properties();
+        let dtstamp = DateTime::try_from(required(properties, "DTSTAMP")?)?;
+        let uid = Text::try_from(required(properties, "UID")?)?;
+        for name in [
+            "CLASS",
+            "COMPLETED",
+            "CREATED",
+            "DESCRIPTION",
+            "DTSTART",
+            "GEO",
+            "LAST-MODIFIED",
+            "LOCATION",
+            "ORGANIZER",
+            "PERCENT-COMPLETE",
+            "PRIORITY",
+            "RECURRENCE-ID",
+            "SEQUENCE",
+            "STATUS",
+            "SUMMARY",
+            "URL",
+            "DUE",
+            "DURATION",
+        ] {
+            optional(properties, name)?;
+        }
+        exclusive(properties, "DUE", "DURATION")?;
+        if find(properties, "DURATION").is_some() && find(properties, "DTSTART").is_none() {
+            return Err(ValidationError::PropertyRequires(
+                PropertyName::new("DURATION"),
+                PropertyName::new("DTSTART"),
+            ));
+        }
+        if let Some(status) = find(properties, "STATUS") {
+            TodoStatus::from_property(status)
+                .ok_or_else(|| ValidationError::InvalidPropertyValue(status.0.clone()))?;
+        }
+
+        let alarms = component
+            .components()
+            .iter()
+            .map(Alarm::try_from)
+            .collect::<Result<Vec<Alarm>, ValidationError>>()?;
+
+        let properties = properties
+            .iter()
+            .cloned()
+            .map(|property| {
+                decode_number(property)
+                    .and_then(decode_text)
+                    .and_then(decode_attach)
+                    .and_then(decode_uri)
+            })
+            .collect::<Result<Vec<Property>, ValidationError>>()?;
+        Ok(Self(dtstamp, uid, properties, alarms))
+    }
+}
+
+impl From<Todo> for Component {
+    fn from(todo: Todo) -> Self {
+        Component(
+            ComponentName::new("VTODO"),
+            todo.2,
+            todo.3.into_iter().map(Component::from).collect(),
+        )
+    }
+}
+
+/// statvalue-todo  = "NEEDS-ACTION" ;Indicates to-do needs action.
+///                 / "COMPLETED"    ;Indicates to-do completed.
+///                 / "IN-PROCESS"   ;Indicates to-do in process of.
+///                 / "CANCELLED"    ;Indicates to-do was cancelled.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.11>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TodoStatus {
+    NeedsAction,
+    Completed,
+    InProcess,
+    Cancelled,
+}
+
+impl TodoStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TodoStatus::NeedsAction => "NEEDS-ACTION",
+            TodoStatus::Completed => "COMPLETED",
+            TodoStatus::InProcess => "IN-PROCESS",
+            TodoStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    fn from_property(property: &Property) -> Option<Self> {
+        match property.value().as_str()?.to_ascii_uppercase().as_str() {
+            "NEEDS-ACTION" => Some(TodoStatus::NeedsAction),
+            "COMPLETED" => Some(TodoStatus::Completed),
+            "IN-PROCESS" => Some(TodoStatus::InProcess),
+            "CANCELLED" => Some(TodoStatus::Cancelled),
+            _ => None,
+        }
+    }
+
+    fn into_property(self) -> Property {
+        Property(
+            PropertyName::new("STATUS"),
+            Default::default(),
+            PropertyValue::Text(Text(self.as_str().to_owned())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, Time, TimeForm,
+        fixtures::{component, property},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "20070313T123432Z-456553@example.com"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("DUE", "20070501"),
+                property("SUMMARY", "Submit Quebec Income Tax Return for 2006"),
+                property("STATUS", "NEEDS-ACTION"),
+            ],
+            vec![],
+        );
+        let Ok(todo) = Todo::try_from(&c) else {
+            panic!("VTODO is invalid");
+        };
+        assert_eq!(todo.due(), Some(&property("DUE", "20070501")));
+        assert_eq!(todo.status(), Some(TodoStatus::NeedsAction));
+        assert_eq!(todo.completed(), None);
+        assert_eq!(
+            todo.summary().map(Property::value),
+            Some(&PropertyValue::Text(Text(
+                "Submit Quebec Income Tax Return for 2006".to_owned()
+            )))
+        );
+        assert_eq!(Todo::try_from(&Component::from(todo.clone())), Ok(todo));
+    }
+
+    #[test]
+    fn test_try_from_due_and_duration() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("DTSTART", "20070313T123432Z"),
+                property("DUE", "20070501"),
+                property("DURATION", "P1D"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Todo::try_from(&c),
+            Err(ValidationError::MutuallyExclusiveProperties(
+                PropertyName::new("DUE"),
+                PropertyName::new("DURATION")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from_duration_without_dtstart() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("DURATION", "P1D"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Todo::try_from(&c),
+            Err(ValidationError::PropertyRequires(
+                PropertyName::new("DURATION"),
+                PropertyName::new("DTSTART")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from_invalid_status() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("STATUS", "TENTATIVE"),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            Todo::try_from(&c),
+            Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                "STATUS"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_numbers() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("PRIORITY", "+1"),
+                property("PERCENT-COMPLETE", "100"),
+                property("SEQUENCE", "0"),
+            ],
+            vec![],
+        );
+        let Ok(todo) = Todo::try_from(&c) else {
+            panic!("VTODO is invalid");
+        };
+        assert_eq!(
+            todo.priority().map(Property::value),
+            Some(&PropertyValue::Integer(1))
+        );
+        assert_eq!(
+            todo.percent_complete().map(Property::value),
+            Some(&PropertyValue::Integer(100))
+        );
+
+        for (name, value) in [
+            ("PRIORITY", "10"),
+            ("PERCENT-COMPLETE", "101"),
+            ("PERCENT-COMPLETE", "-1"),
+            ("SEQUENCE", "-1"),
+            ("SEQUENCE", "4294967296"),
+            ("PRIORITY", "-99999999999"),
+            ("GEO", "91;0"),
+        ] {
+            let c = component(
+                "VTODO",
+                vec![
+                    property("UID", "1"),
+                    property("DTSTAMP", "20070313T123432Z"),
+                    property(name, value),
+                ],
+                vec![],
+            );
+            assert_eq!(
+                Todo::try_from(&c),
+                Err(ValidationError::PropertyValueOutOfRange(PropertyName::new(
+                    name
+                )))
+            );
+        }
+
+        for (name, value) in [("SEQUENCE", "1.5"), ("SEQUENCE", "+"), ("GEO", "0")] {
+            let c = component(
+                "VTODO",
+                vec![
+                    property("UID", "1"),
+                    property("DTSTAMP", "20070313T123432Z"),
+                    property(name, value),
+                ],
+                vec![],
+            );
+            assert_eq!(
+                Todo::try_from(&c),
+                Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                    name
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_complete_and_reopen() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("STATUS", "IN-PROCESS"),
+                property("PERCENT-COMPLETE", "50"),
+            ],
+            vec![],
+        );
+        let Ok(mut todo) = Todo::try_from(&c) else {
+            panic!("VTODO is invalid");
+        };
+
+        let completed = DateTime(Date(2007, 7, 7), Time(10, 0, 0, TimeForm::Utc));
+        assert_eq!(todo.complete(completed.clone()), Ok(()));
+        assert_eq!(todo.status(), Some(TodoStatus::Completed));
+        assert_eq!(
+            todo.completed(),
+            Some(&Property(
+                PropertyName::new("COMPLETED"),
+                Default::default(),
+                PropertyValue::DateTime(completed),
+            ))
+        );
+        assert_eq!(
+            todo.percent_complete(),
+            Some(&Property(
+                PropertyName::new("PERCENT-COMPLETE"),
+                Default::default(),
+                PropertyValue::Integer(100),
+            ))
+        );
+        assert!(Todo::try_from(&Component::from(todo.clone())).is_ok());
+
+        todo.reopen();
+        assert_eq!(todo.status(), Some(TodoStatus::NeedsAction));
+        assert_eq!(todo.completed(), None);
+        assert_eq!(todo.percent_complete(), None);
+        assert!(Todo::try_from(&Component::from(todo)).is_ok());
+    }
+
+    #[test]
+    fn test_complete_not_in_utc() {
+        let c = component(
+            "VTODO",
+            vec![
+                property("UID", "1"),
+                property("DTSTAMP", "20070313T123432Z"),
+                property("STATUS", "IN-PROCESS"),
+            ],
+            vec![],
+        );
+        let Ok(mut todo) = Todo::try_from(&c) else {
+            panic!("VTODO is invalid");
+        };
+        let before = todo.clone();
+
+        for form in [
+            TimeForm::Floating,
+            TimeForm::Zoned("America/New_York".to_owned()),
+        ] {
+            let completed = DateTime(Date(2007, 7, 7), Time(10, 0, 0, form));
+            assert_eq!(
+                todo.complete(completed),
+                Err(ValidationError::InvalidPropertyValue(PropertyName::new(
+                    "COMPLETED"
+                )))
+            );
+            assert_eq!(todo, before);
+        }
+    }
+}
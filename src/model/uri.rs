@@ -0,0 +1,119 @@
+use std::{fmt, ops::Range};
+
+use nom::Parser;
+
+use crate::parser::uri;
+
+/// URI
+///
+/// uri = <As defined in Section 3 of [RFC3986]>
+///
+/// URI = scheme ":" hier-part [ "?" query ] [ "#" fragment ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.13>
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-3>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Uri(pub(crate) String, pub(crate) UriComponents);
+
+impl Uri {
+    /// Returns `None` if `s` is not a URI.
+    pub fn new(s: &str) -> Option<Self> {
+        nom::combinator::all_consuming(uri)
+            .parse(s)
+            .ok()
+            .map(|(_, uri)| uri)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn scheme(&self) -> &str {
+        &self.0[self.1.scheme.clone()]
+    }
+
+    /// The authority, without the leading "//".
+    pub fn authority(&self) -> Option<&str> {
+        self.1.authority.clone().map(|range| &self.0[range])
+    }
+
+    pub fn path(&self) -> &str {
+        &self.0[self.1.path.clone()]
+    }
+
+    /// The query, without the leading "?".
+    pub fn query(&self) -> Option<&str> {
+        self.1.query.clone().map(|range| &self.0[range])
+    }
+
+    /// The fragment, without the leading "#".
+    pub fn fragment(&self) -> Option<&str> {
+        self.1.fragment.clone().map(|range| &self.0[range])
+    }
+}
+
+/// The byte ranges of the components of a URI, located by the parser.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct UriComponents {
+    pub(crate) scheme: Range<usize>,
+    pub(crate) authority: Option<Range<usize>>,
+    pub(crate) path: Range<usize>,
+    pub(crate) query: Option<Range<usize>>,
+    pub(crate) fragment: Option<Range<usize>>,
+}
+
+impl fmt::Display for Uri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let Some(uri) = Uri::new("http://user@example.com:8080/a/b.ics?x=1&y=2#frag") else {
+            panic!("not a URI");
+        };
+        assert_eq!(uri.scheme(), "http");
+        assert_eq!(uri.authority(), Some("user@example.com:8080"));
+        assert_eq!(uri.path(), "/a/b.ics");
+        assert_eq!(uri.query(), Some("x=1&y=2"));
+        assert_eq!(uri.fragment(), Some("frag"));
+
+        let Some(uri) = Uri::new("mailto:jane_doe@example.com") else {
+            panic!("not a URI");
+        };
+        assert_eq!(uri.scheme(), "mailto");
+        assert_eq!(uri.authority(), None);
+        assert_eq!(uri.path(), "jane_doe@example.com");
+        assert_eq!(uri.query(), None);
+        assert_eq!(uri.fragment(), None);
+
+        let Some(uri) =
+            Uri::new("ldap://example.com:6666/o=ABC%20Industries,c=US???(cn=Jim%20Dolittle)")
+        else {
+            panic!("not a URI");
+        };
+        assert_eq!(uri.scheme(), "ldap");
+        assert_eq!(uri.authority(), Some("example.com:6666"));
+        assert_eq!(uri.path(), "/o=ABC%20Industries,c=US");
+        assert_eq!(uri.query(), Some("??(cn=Jim%20Dolittle)"));
+
+        let Some(uri) = Uri::new("file://") else {
+            panic!("not a URI");
+        };
+        assert_eq!(uri.authority(), Some(""));
+        assert_eq!(uri.path(), "");
+
+        let Some(uri) = Uri::new("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6") else {
+            panic!("not a URI");
+        };
+        assert_eq!(uri.scheme(), "urn");
+        assert_eq!(uri.path(), "uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6");
+
+        assert_eq!(Uri::new("example.com"), None);
+    }
+}
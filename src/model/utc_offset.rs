@@ -0,0 +1,142 @@
+use std::fmt;
+
+use nom::Parser;
+
+use crate::{
+    error::ValidationError,
+    model::{DateTime, Duration, Property, PropertyValue, Time, TimeForm},
+    parser::utc_offset,
+};
+
+/// UTC-OFFSET
+///
+/// utc-offset = time-numzone
+///
+/// time-numzone = ("+" / "-") time-hour time-minute [time-second]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.14>
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UtcOffset(pub(crate) i32);
+
+impl UtcOffset {
+    /// Returns `None` if the offset is not less than 24 hours either way.
+    pub fn new(seconds: i32) -> Option<Self> {
+        if seconds.abs() >= 24 * 3600 {
+            return None;
+        }
+        Some(Self(seconds))
+    }
+
+    /// The offset in seconds, positive east of UTC.
+    pub fn seconds(&self) -> i32 {
+        self.0
+    }
+
+    /// Converts a local time in this offset to UTC.
+    ///
+    /// Returns `None` for a UTC time or if the result is out of range.
+    pub fn to_utc(&self, local: &DateTime) -> Option<DateTime> {
+        if local.is_utc() {
+            return None;
+        }
+        let DateTime(date, Time(hour, minute, second, _)) =
+            local.checked_sub(Duration(0, i64::from(self.0)))?;
+        Some(DateTime(date, Time(hour, minute, second, TimeForm::Utc)))
+    }
+
+    /// Converts a UTC time to a floating local time in this offset.
+    ///
+    /// Returns `None` for a local time or if the result is out of range.
+    pub fn from_utc(&self, utc: &DateTime) -> Option<DateTime> {
+        if !utc.is_utc() {
+            return None;
+        }
+        let DateTime(date, Time(hour, minute, second, _)) =
+            utc.checked_add(Duration(0, i64::from(self.0)))?;
+        Some(DateTime(
+            date,
+            Time(hour, minute, second, TimeForm::Floating),
+        ))
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let seconds = self.0.unsigned_abs();
+        write!(f, "{}{:02}{:02}", sign, seconds / 3600, seconds / 60 % 60)?;
+        if !seconds.is_multiple_of(60) {
+            write!(f, "{:02}", seconds % 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&Property> for UtcOffset {
+    type Error = ValidationError;
+
+    /// Reads a UTC-OFFSET property value.
+    fn try_from(property: &Property) -> Result<Self, Self::Error> {
+        let invalid = || ValidationError::InvalidPropertyValue(property.0.clone());
+        match property.value() {
+            PropertyValue::UtcOffset(offset) => Ok(*offset),
+            PropertyValue::XType(s) => nom::combinator::all_consuming(utc_offset)
+                .parse(s.as_str())
+                .map(|(_, offset)| offset)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Date;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(UtcOffset::new(-5 * 3600), Some(UtcOffset(-18000)));
+        assert_eq!(UtcOffset::new(24 * 3600), None);
+    }
+
+    #[test]
+    fn test_to_utc() {
+        let local = DateTime(Date(1998, 1, 19), Time(20, 0, 0, TimeForm::Floating));
+        assert_eq!(
+            UtcOffset(-5 * 3600).to_utc(&local),
+            Some(DateTime(Date(1998, 1, 20), Time(1, 0, 0, TimeForm::Utc)))
+        );
+        let local = DateTime(
+            Date(1998, 1, 1),
+            Time(0, 30, 0, TimeForm::Zoned("Asia/Kolkata".to_owned())),
+        );
+        assert_eq!(
+            UtcOffset(5 * 3600 + 30 * 60).to_utc(&local),
+            Some(DateTime(Date(1997, 12, 31), Time(19, 0, 0, TimeForm::Utc)))
+        );
+        let utc = DateTime(Date(1998, 1, 19), Time(20, 0, 0, TimeForm::Utc));
+        assert_eq!(UtcOffset(3600).to_utc(&utc), None);
+    }
+
+    #[test]
+    fn test_from_utc() {
+        let utc = DateTime(Date(1998, 1, 20), Time(1, 0, 0, TimeForm::Utc));
+        assert_eq!(
+            UtcOffset(-5 * 3600).from_utc(&utc),
+            Some(DateTime(
+                Date(1998, 1, 19),
+                Time(20, 0, 0, TimeForm::Floating)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(UtcOffset(-5 * 3600).to_string(), "-0500");
+        assert_eq!(UtcOffset(3600).to_string(), "+0100");
+        assert_eq!(UtcOffset(0).to_string(), "+0000");
+        assert_eq!(UtcOffset(-(3600 + 30 * 60 + 15)).to_string(), "-013015");
+    }
+}
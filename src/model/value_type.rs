@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::model::is_x_name;
+
+/// valuetype  = ("BINARY"
+///            / "BOOLEAN"
+///            / "CAL-ADDRESS"
+///            / "DATE"
+///            / "DATE-TIME"
+///            / "DURATION"
+///            / "FLOAT"
+///            / "INTEGER"
+///            / "PERIOD"
+///            / "RECUR"
+///            / "TEXT"
+///            / "TIME"
+///            / "URI"
+///            / "UTC-OFFSET"
+///            / x-name
+///            ; Some experimental iCalendar value type.
+///            / iana-token)
+///            ; Some other IANA-registered iCalendar value type.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.20>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ValueType {
+    Binary,
+    Boolean,
+    CalAddress,
+    Date,
+    DateTime,
+    Duration,
+    Float,
+    Integer,
+    Period,
+    Recur,
+    Text,
+    Time,
+    Uri,
+    UtcOffset,
+    XName(String),
+    IanaToken(String),
+}
+
+impl ValueType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ValueType::Binary => "BINARY",
+            ValueType::Boolean => "BOOLEAN",
+            ValueType::CalAddress => "CAL-ADDRESS",
+            ValueType::Date => "DATE",
+            ValueType::DateTime => "DATE-TIME",
+            ValueType::Duration => "DURATION",
+            ValueType::Float => "FLOAT",
+            ValueType::Integer => "INTEGER",
+            ValueType::Period => "PERIOD",
+            ValueType::Recur => "RECUR",
+            ValueType::Text => "TEXT",
+            ValueType::Time => "TIME",
+            ValueType::Uri => "URI",
+            ValueType::UtcOffset => "UTC-OFFSET",
+            ValueType::XName(s) | ValueType::IanaToken(s) => s,
+        }
+    }
+
+    /// Returns the default value type of the property defined by RFC 5545,
+    /// or `None` for an x-prop or an iana-prop.
+    pub fn default_for(name: &str) -> Option<Self> {
+        Some(match name {
+            "CALSCALE" | "METHOD" | "PRODID" | "VERSION" | "CATEGORIES" | "CLASS" | "COMMENT"
+            | "DESCRIPTION" | "LOCATION" | "RESOURCES" | "STATUS" | "SUMMARY" | "TRANSP"
+            | "TZID" | "TZNAME" | "CONTACT" | "RELATED-TO" | "UID" | "ACTION"
+            | "REQUEST-STATUS" => ValueType::Text,
+            "ATTACH" | "TZURL" | "URL" => ValueType::Uri,
+            "GEO" => ValueType::Float,
+            "PERCENT-COMPLETE" | "PRIORITY" | "REPEAT" | "SEQUENCE" => ValueType::Integer,
+            "COMPLETED" | "DTEND" | "DUE" | "DTSTART" | "RECURRENCE-ID" | "EXDATE" | "RDATE"
+            | "CREATED" | "DTSTAMP" | "LAST-MODIFIED" => ValueType::DateTime,
+            "DURATION" | "TRIGGER" => ValueType::Duration,
+            "FREEBUSY" => ValueType::Period,
+            "TZOFFSETFROM" | "TZOFFSETTO" => ValueType::UtcOffset,
+            "ATTENDEE" | "ORGANIZER" => ValueType::CalAddress,
+            "RRULE" => ValueType::Recur,
+            _ => return None,
+        })
+    }
+
+    /// Checks whether the VALUE parameter may set the value type of the
+    /// property. Any value type is allowed for an x-prop or an iana-prop.
+    pub fn is_allowed_for(&self, name: &str) -> bool {
+        let Some(default) = Self::default_for(name) else {
+            return true;
+        };
+        if *self == default {
+            return true;
+        }
+        matches!(
+            (name, self),
+            (
+                "DTSTART" | "DTEND" | "DUE" | "RECURRENCE-ID" | "EXDATE",
+                ValueType::Date
+            ) | ("RDATE", ValueType::Date | ValueType::Period)
+                | ("ATTACH", ValueType::Binary)
+                | ("TRIGGER", ValueType::DateTime)
+        )
+    }
+}
+
+impl From<&str> for ValueType {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "BINARY" => ValueType::Binary,
+            "BOOLEAN" => ValueType::Boolean,
+            "CAL-ADDRESS" => ValueType::CalAddress,
+            "DATE" => ValueType::Date,
+            "DATE-TIME" => ValueType::DateTime,
+            "DURATION" => ValueType::Duration,
+            "FLOAT" => ValueType::Float,
+            "INTEGER" => ValueType::Integer,
+            "PERIOD" => ValueType::Period,
+            "RECUR" => ValueType::Recur,
+            "TEXT" => ValueType::Text,
+            "TIME" => ValueType::Time,
+            "URI" => ValueType::Uri,
+            "UTC-OFFSET" => ValueType::UtcOffset,
+            _ if is_x_name(s) => ValueType::XName(s.to_owned()),
+            _ => ValueType::IanaToken(s.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_for() {
+        assert_eq!(ValueType::default_for("DTSTART"), Some(ValueType::DateTime));
+        assert_eq!(ValueType::default_for("SUMMARY"), Some(ValueType::Text));
+        assert_eq!(ValueType::default_for("X-ABC"), None);
+        assert_eq!(ValueType::default_for("NAME"), None);
+    }
+
+    #[test]
+    fn test_is_allowed_for() {
+        assert!(ValueType::DateTime.is_allowed_for("DTSTART"));
+        assert!(ValueType::Date.is_allowed_for("DTSTART"));
+        assert!(ValueType::Period.is_allowed_for("RDATE"));
+        assert!(!ValueType::Period.is_allowed_for("DTSTART"));
+        assert!(!ValueType::Text.is_allowed_for("DTSTAMP"));
+        assert!(ValueType::Integer.is_allowed_for("X-ABC"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(ValueType::from("DATE-TIME"), ValueType::DateTime);
+        assert_eq!(
+            ValueType::from("X-ABC"),
+            ValueType::XName("X-ABC".to_owned())
+        );
+        assert_eq!(
+            ValueType::from("ABC"),
+            ValueType::IanaToken("ABC".to_owned())
+        );
+        assert_eq!(ValueType::from("date-time"), ValueType::DateTime);
+        assert_eq!(
+            ValueType::from("x-Abc"),
+            ValueType::XName("x-Abc".to_owned())
+        );
+        assert_eq!(
+            ValueType::from("Abc"),
+            ValueType::IanaToken("Abc".to_owned())
+        );
+    }
+}
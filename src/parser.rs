@@ -4,59 +4,134 @@ mod binary;
 mod boolean;
 mod cal_address;
 mod calprops;
+mod calscale;
+mod calvalue;
+mod caret_escape;
 mod contentline;
 mod date_fullyear;
 mod date_mday;
 mod date_month;
+mod date_time;
 mod date_value;
+mod dur_date;
+mod dur_day;
+mod dur_hour;
+mod dur_minute;
+mod dur_second;
+mod dur_time;
+mod dur_value;
+mod dur_week;
 mod escaped_char;
+mod fbvalue;
+mod float;
+mod fmttype;
+mod geovalue;
+mod iana_prop;
 mod iana_token;
+mod integer;
+mod method;
+mod metvalue;
 mod name;
 mod non_us_ascii;
+mod other_param;
 mod param;
 mod param_name;
 mod param_value;
 mod paramtext;
+mod parse_calendar;
+mod period;
+mod period_explicit;
+mod period_start;
 mod pidvalue;
 mod prodid;
+mod property;
 mod qsafe_char;
 mod quoted_string;
 mod safe_char;
+mod statcode;
 mod text;
+mod time;
+mod time_hour;
+mod time_minute;
+mod time_second;
 mod tsafe_char;
+mod unfold;
+mod uri;
+mod utc_offset;
 mod value;
 mod value_char;
 mod vendorid;
+mod version;
+mod vervalue;
 mod wsp;
 mod x_name;
+mod x_prop;
 
 pub use self::b_char::b_char;
 pub use self::b_end::b_end;
 pub use self::binary::binary;
 pub use self::boolean::boolean;
 pub use self::cal_address::cal_address;
-pub use self::calprops::calprops;
-pub use self::contentline::contentline;
+pub use self::calprops::{calprop, calprops};
+pub use self::calscale::calscale;
+pub use self::calvalue::calvalue;
+pub use self::caret_escape::caret_escape;
+pub use self::contentline::{ContentLine, contentline};
 pub use self::date_fullyear::date_fullyear;
 pub use self::date_mday::date_mday;
 pub use self::date_month::date_month;
+pub use self::date_time::date_time;
+pub use self::date_value::date_value;
+pub use self::dur_date::dur_date;
+pub use self::dur_day::dur_day;
+pub use self::dur_hour::dur_hour;
+pub use self::dur_minute::dur_minute;
+pub use self::dur_second::dur_second;
+pub use self::dur_time::dur_time;
+pub use self::dur_value::dur_value;
+pub use self::dur_week::dur_week;
 pub use self::escaped_char::escaped_char;
+pub use self::fbvalue::fbvalue;
+pub use self::float::float;
+pub use self::fmttype::fmttype;
+pub use self::geovalue::geovalue;
+pub use self::iana_prop::iana_prop;
 pub use self::iana_token::iana_token;
+pub use self::integer::integer;
+pub use self::method::method;
+pub use self::metvalue::metvalue;
 pub use self::name::name;
 pub use self::non_us_ascii::non_us_ascii;
+pub use self::other_param::other_param;
 pub use self::param::param;
 pub use self::param_name::param_name;
 pub use self::param_value::param_value;
 pub use self::paramtext::paramtext;
+pub use self::parse_calendar::{parse_calendar, parse_calendar_lenient};
+pub use self::period::period;
+pub use self::period_explicit::period_explicit;
+pub use self::period_start::period_start;
 pub use self::pidvalue::pidvalue;
 pub use self::prodid::prodid;
+pub use self::property::{property, property_value};
 pub use self::qsafe_char::qsafe_char;
 pub use self::quoted_string::quoted_string;
 pub use self::safe_char::safe_char;
+pub use self::statcode::statcode;
 pub use self::text::text;
+pub use self::time::time;
+pub use self::time_hour::time_hour;
+pub use self::time_minute::time_minute;
+pub use self::time_second::time_second;
 pub use self::tsafe_char::tsafe_char;
+pub use self::unfold::{LogicalLine, Unfold, unfold};
+pub use self::uri::uri;
+pub use self::utc_offset::utc_offset;
 pub use self::value::value;
 pub use self::value_char::value_char;
 pub use self::vendorid::vendorid;
+pub use self::version::version;
+pub use self::vervalue::vervalue;
 pub use self::wsp::wsp;
 pub use self::x_name::x_name;
+pub use self::x_prop::x_prop;
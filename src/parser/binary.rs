@@ -6,6 +6,8 @@ use crate::parser::{b_char, b_end};
 /// binary     = *(4b-char) [b-end]
 /// ; A "BASE64" encoded character string, as defined by [RFC4648].
 ///
+/// The value is decoded to its octets.
+///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.1>
 pub fn binary(input: &str) -> IResult<&str, Binary> {
     (
@@ -22,7 +24,7 @@ pub fn binary(input: &str) -> IResult<&str, Binary> {
             if let Some(b_end) = b_end {
                 s.push_str(&b_end);
             }
-            Binary(s)
+            Binary::decode(&s)
         })
         .parse(input)
 }
@@ -35,23 +37,30 @@ mod tests {
     #[test]
     fn test() {
         let input = "";
-        let expected = Ok(("", Binary("".to_owned())));
+        let expected = Ok(("", Binary(vec![])));
         assert_eq!(binary(input), expected);
 
         let input = "abcd";
-        let expected = Ok(("", Binary("abcd".to_owned())));
+        let expected = Ok(("", Binary(vec![0x69, 0xb7, 0x1d])));
         assert_eq!(binary(input), expected);
 
         let input = "abcdabcd";
-        let expected = Ok(("", Binary("abcdabcd".to_owned())));
+        let expected = Ok(("", Binary(vec![0x69, 0xb7, 0x1d, 0x69, 0xb7, 0x1d])));
         assert_eq!(binary(input), expected);
 
         let input = "abcdabcdabc=";
-        let expected = Ok(("", Binary("abcdabcdabc=".to_owned())));
+        let expected = Ok((
+            "",
+            Binary(vec![0x69, 0xb7, 0x1d, 0x69, 0xb7, 0x1d, 0x69, 0xb7]),
+        ));
         assert_eq!(binary(input), expected);
 
         let input = "abcdabcdab==";
-        let expected = Ok(("", Binary("abcdabcdab==".to_owned())));
+        let expected = Ok(("", Binary(vec![0x69, 0xb7, 0x1d, 0x69, 0xb7, 0x1d, 0x69])));
+        assert_eq!(binary(input), expected);
+
+        let input = "VGhlIHF1aWNrIGJyb3duIGZveA==";
+        let expected = Ok(("", Binary(b"The quick brown fox".to_vec())));
         assert_eq!(binary(input), expected);
     }
 }
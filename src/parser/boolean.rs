@@ -7,8 +7,8 @@ use crate::model::Boolean;
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.2>
 pub fn boolean(input: &str) -> IResult<&str, Boolean> {
     nom::branch::alt((
-        nom::bytes::complete::tag("TRUE").map(|_| Boolean::True),
-        nom::bytes::complete::tag("FALSE").map(|_| Boolean::False),
+        nom::bytes::complete::tag_no_case("TRUE").map(|_| Boolean::True),
+        nom::bytes::complete::tag_no_case("FALSE").map(|_| Boolean::False),
     ))
     .parse(input)
 }
@@ -28,6 +28,10 @@ mod tests {
         let expected = Ok(("", Boolean::False));
         assert_eq!(boolean(input), expected);
 
+        let input = "true";
+        let expected = Ok(("", Boolean::True));
+        assert_eq!(boolean(input), expected);
+
         let input = "INVALID";
         assert!(boolean(input).is_err());
     }
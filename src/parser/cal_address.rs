@@ -1,17 +1,12 @@
-use nom::IResult;
+use nom::{IResult, Parser};
 
-use crate::model::CalendarUserAddress;
+use crate::{model::CalendarUserAddress, parser::uri};
 
 /// cal-address        = uri
 ///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.3>
 pub fn cal_address(input: &str) -> IResult<&str, CalendarUserAddress> {
-    let (i, t) =
-        nom_uri::uri(nom_locate::LocatedSpan::new(input)).map_err(|e| e.map_input(|_| input))?;
-    Ok((
-        i.fragment(),
-        CalendarUserAddress(t.span.fragment().to_string()),
-    ))
+    uri.map(|uri| CalendarUserAddress(uri.0)).parse(input)
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
 use nom::{IResult, Parser};
 
-use crate::{model::Property, parser::prodid};
+use crate::{
+    model::Property,
+    parser::{calscale, iana_prop, method, prodid, version, x_prop},
+};
 
 /// calprops   = *(
 ///               ;
@@ -21,10 +24,96 @@ use crate::{model::Property, parser::prodid};
 ///               ;
 ///               )
 ///
+/// The number of occurrences is checked by
+/// [`CalendarObject`](crate::model::CalendarObject), not by this parser.
+///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.6>
 pub fn calprops(input: &str) -> IResult<&str, Vec<Property>> {
-    // FIXME
-    nom::multi::many0(prodid).parse(input)
+    nom::multi::many0(calprop).parse(input)
+}
+
+/// One of the properties in `calprops`.
+pub fn calprop(input: &str) -> IResult<&str, Property> {
+    nom::branch::alt((
+        prodid,
+        version,
+        calscale,
+        method,
+        x_prop,
+        // a malformed PRODID (and so on) must not be accepted as an iana-prop
+        nom::combinator::verify(iana_prop, |property: &Property| {
+            !matches!(
+                property.name().as_str(),
+                "PRODID" | "VERSION" | "CALSCALE" | "METHOD"
+            )
+        }),
+    ))
+    .parse(input)
 }
 
-// TODO: tests
+#[cfg(test)]
+mod tests {
+    use crate::model::{PropertyName, PropertyValue, Text};
+
+    use super::*;
+
+    #[test]
+    fn test_calprops() {
+        let input = [
+            "PRODID:-//Example Corp//NONSGML Example//EN\r\n",
+            "VERSION:2.0\r\n",
+            "CALSCALE:GREGORIAN\r\n",
+            "METHOD:PUBLISH\r\n",
+            "X-WR-CALNAME:Example\r\n",
+            "NAME:Example\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            calprops(&input),
+            Ok((
+                "",
+                vec![
+                    Property(
+                        PropertyName::new("PRODID"),
+                        Default::default(),
+                        PropertyValue::Text(Text(
+                            "-//Example Corp//NONSGML Example//EN".to_owned()
+                        )),
+                    ),
+                    Property(
+                        PropertyName::new("VERSION"),
+                        Default::default(),
+                        PropertyValue::Text(Text("2.0".to_owned())),
+                    ),
+                    Property(
+                        PropertyName::new("CALSCALE"),
+                        Default::default(),
+                        PropertyValue::Text(Text("GREGORIAN".to_owned())),
+                    ),
+                    Property(
+                        PropertyName::new("METHOD"),
+                        Default::default(),
+                        PropertyValue::Text(Text("PUBLISH".to_owned())),
+                    ),
+                    Property(
+                        PropertyName::new("X-WR-CALNAME"),
+                        Default::default(),
+                        PropertyValue::XType("Example".to_owned()),
+                    ),
+                    Property(
+                        PropertyName::new("NAME"),
+                        Default::default(),
+                        PropertyValue::XType("Example".to_owned()),
+                    ),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_calprop_rejects_malformed_known_properties() {
+        assert!(calprop("VERSION:\r\n").is_err());
+        assert!(calprop("CALSCALE:JULIAN\r\n").is_err());
+        assert!(calprop("METHOD:A B\r\n").is_err());
+    }
+}
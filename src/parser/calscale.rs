@@ -0,0 +1,55 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Property,
+    parser::{calvalue, other_param},
+};
+
+/// calscale   = "CALSCALE" calparam ":" calvalue CRLF
+///
+/// calparam   = *(";" other-param)
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.1>
+pub fn calscale(input: &str) -> IResult<&str, Property> {
+    (
+        nom::bytes::complete::tag_no_case("CALSCALE"),
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            other_param,
+        )),
+        nom::character::complete::char(':'),
+        calvalue,
+        nom::character::complete::line_ending,
+    )
+        .map(|(name, calparam, _, calvalue, _)| Property::new(name.to_owned(), calparam, calvalue))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{PropertyName, PropertyValue, Text};
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let input = "CALSCALE:GREGORIAN\r\n";
+        assert_eq!(
+            calscale(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("CALSCALE"),
+                    Default::default(),
+                    PropertyValue::Text(Text("GREGORIAN".to_owned())),
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(calscale("CALSCALE:JULIAN\r\n").is_err());
+        assert!(calscale("INVALID:GREGORIAN\r\n").is_err());
+    }
+}
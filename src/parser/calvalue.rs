@@ -0,0 +1,32 @@
+use nom::{IResult, Parser};
+
+use crate::model::{PropertyValue, Text};
+
+/// calvalue   = "GREGORIAN"
+///
+/// The value is case-insensitive, so it is kept in uppercase.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.1>
+pub fn calvalue(input: &str) -> IResult<&str, PropertyValue> {
+    nom::bytes::complete::tag_no_case("GREGORIAN")
+        .map(|s: &str| PropertyValue::Text(Text(s.to_ascii_uppercase())))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calvalue() {
+        assert_eq!(
+            calvalue("GREGORIAN"),
+            Ok(("", PropertyValue::Text(Text("GREGORIAN".to_owned()))))
+        );
+        assert_eq!(
+            calvalue("gregorian"),
+            Ok(("", PropertyValue::Text(Text("GREGORIAN".to_owned()))))
+        );
+        assert!(calvalue("JULIAN").is_err());
+    }
+}
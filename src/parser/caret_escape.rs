@@ -0,0 +1,37 @@
+use nom::{IResult, Parser};
+
+/// param-value characters encoded with the caret
+///
+/// ^n  -> a newline
+/// ^^  -> "^"
+/// ^'  -> DQUOTE
+///
+/// A caret followed by any other character is left as it is.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6868#section-3>
+pub fn caret_escape(input: &str) -> IResult<&str, char> {
+    nom::sequence::preceded(
+        nom::character::complete::char('^'),
+        nom::branch::alt((
+            nom::character::complete::char('n').map(|_| '\n'),
+            nom::character::complete::char('^'),
+            nom::character::complete::char('\'').map(|_| '"'),
+        )),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret_escape() {
+        assert_eq!(caret_escape("^n"), Ok(("", '\n')));
+        assert_eq!(caret_escape("^^"), Ok(("", '^')));
+        assert_eq!(caret_escape("^'"), Ok(("", '"')));
+        assert!(caret_escape("^a").is_err());
+        assert!(caret_escape("^N").is_err());
+        assert!(caret_escape("n").is_err());
+    }
+}
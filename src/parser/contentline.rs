@@ -2,6 +2,10 @@ use nom::{IResult, Parser};
 
 use crate::parser::{name, param, value};
 
+/// The name, the parameters and the value of a content line, the value not
+/// typed yet.
+pub type ContentLine = (String, Vec<(String, Vec<String>)>, String);
+
 /// contentline   = name *(";" param ) ":" value CRLF
 /// ; This ABNF is just a general definition for an initial parsing
 /// ; of the content line into its property name, parameter list,
@@ -13,7 +17,7 @@ use crate::parser::{name, param, value};
 /// ; the folding procedure described above.
 ///
 /// <https://datatracker.ietf.org/doc/html/rfc5545>
-pub fn contentline(input: &str) -> IResult<&str, (String, Vec<(String, Vec<String>)>, String)> {
+pub fn contentline(input: &str) -> IResult<&str, ContentLine> {
     (
         name,
         nom::multi::many0((nom::character::complete::char(';'), param)),
@@ -4,10 +4,15 @@ use nom::{IResult, Parser};
 ///                                    ;based on month/year
 ///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.4>
-pub fn date_mday(input: &str) -> IResult<&str, String> {
-    nom::multi::count(nom::character::satisfy(|c| c.is_ascii_digit()), 2)
-        .parse(input)
-        .map(|(i, digits)| (i, digits.iter().collect::<String>()))
+pub fn date_mday(input: &str) -> IResult<&str, u8> {
+    nom::combinator::verify(
+        nom::combinator::map_res(
+            nom::bytes::complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+            str::parse,
+        ),
+        |mday: &u8| (1..=31).contains(mday),
+    )
+    .parse(input)
 }
 
 #[cfg(test)]
@@ -16,13 +21,13 @@ mod tests {
 
     #[test]
     fn test_date_mday() {
-        assert_eq!(date_mday("01"), Ok(("", "01".to_owned())));
-        assert_eq!(date_mday("31"), Ok(("", "31".to_owned())));
-        assert_eq!(date_mday("15"), Ok(("", "15".to_owned())));
-        assert_eq!(date_mday("28"), Ok(("", "28".to_owned())));
+        assert_eq!(date_mday("01"), Ok(("", 1)));
+        assert_eq!(date_mday("31"), Ok(("", 31)));
+        assert_eq!(date_mday("15"), Ok(("", 15)));
+        assert_eq!(date_mday("28"), Ok(("", 28)));
 
-        assert_eq!(date_mday("02rest"), Ok(("rest", "02".to_owned())));
-        assert_eq!(date_mday("15T"), Ok(("T", "15".to_owned())));
+        assert_eq!(date_mday("02rest"), Ok(("rest", 2)));
+        assert_eq!(date_mday("15T"), Ok(("T", 15)));
 
         let result = date_mday("");
         assert!(result.is_err());
@@ -31,12 +36,21 @@ mod tests {
         assert!(result.is_err());
 
         let result = date_mday("123");
-        assert_eq!(result, Ok(("3", "12".to_owned())));
+        assert_eq!(result, Ok(("3", 12)));
 
         let result = date_mday("ab");
         assert!(result.is_err());
 
         let result = date_mday("A2");
         assert!(result.is_err());
+
+        let result = date_mday("00");
+        assert!(result.is_err());
+
+        let result = date_mday("32");
+        assert!(result.is_err());
+
+        let result = date_mday("99");
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,53 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::DateTime,
+    parser::{date_value, time},
+};
+
+/// date-time  = date "T" time ;As specified in the DATE and TIME
+///                            ;value definitions
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5>
+pub fn date_time(input: &str) -> IResult<&str, DateTime> {
+    (date_value, nom::character::complete::char('T'), time)
+        .map(|(date, _, time)| DateTime(date, time))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, Time, TimeForm};
+
+    use super::*;
+
+    #[test]
+    fn test_date_time() {
+        assert_eq!(
+            date_time("19980118T230000"),
+            Ok((
+                "",
+                DateTime(Date(1998, 1, 18), Time(23, 0, 0, TimeForm::Floating))
+            ))
+        );
+        assert_eq!(
+            date_time("19980119T070000Z"),
+            Ok((
+                "",
+                DateTime(Date(1998, 1, 19), Time(7, 0, 0, TimeForm::Utc))
+            ))
+        );
+        assert_eq!(
+            date_time("19970308T160000Z/PT8H30M"),
+            Ok((
+                "/PT8H30M",
+                DateTime(Date(1997, 3, 8), Time(16, 0, 0, TimeForm::Utc))
+            ))
+        );
+        assert!(date_time("19980118").is_err());
+        assert!(date_time("19980118T").is_err());
+        assert!(date_time("19980118 230000").is_err());
+        assert!(date_time("19980230T230000").is_err());
+        assert!(date_time("19980118T240000").is_err());
+    }
+}
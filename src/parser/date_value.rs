@@ -1,14 +1,19 @@
 use nom::{IResult, Parser};
 
-use crate::parser::{date_fullyear, date_mday, date_month};
+use crate::{
+    model::Date,
+    parser::{date_fullyear, date_mday, date_month},
+};
 
 /// date-value         = date-fullyear date-month date-mday
 ///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.4>
-pub fn date_value(input: &str) -> IResult<&str, String> {
-    (date_fullyear, date_month, date_mday)
-        .map(|(fullyear, month, mday)| format!("{}{}{}", fullyear, month, mday))
-        .parse(input)
+pub fn date_value(input: &str) -> IResult<&str, Date> {
+    nom::combinator::map_opt(
+        (date_fullyear, date_month, date_mday),
+        |(year, month, mday)| Date::new(year, month, mday),
+    )
+    .parse(input)
 }
 
 #[cfg(test)]
@@ -17,19 +22,16 @@ mod tests {
 
     #[test]
     fn test_date_value() {
-        assert_eq!(date_value("19970714"), Ok(("", "19970714".to_owned())));
+        assert_eq!(date_value("19970714"), Ok(("", Date(1997, 7, 14))));
 
-        assert_eq!(date_value("20230101"), Ok(("", "20230101".to_owned())));
-        assert_eq!(date_value("19991231"), Ok(("", "19991231".to_owned())));
-        assert_eq!(date_value("20000229"), Ok(("", "20000229".to_owned())));
+        assert_eq!(date_value("20230101"), Ok(("", Date(2023, 1, 1))));
+        assert_eq!(date_value("19991231"), Ok(("", Date(1999, 12, 31))));
+        assert_eq!(date_value("20000229"), Ok(("", Date(2000, 2, 29))));
 
-        assert_eq!(
-            date_value("20230101rest"),
-            Ok(("rest", "20230101".to_owned()))
-        );
+        assert_eq!(date_value("20230101rest"), Ok(("rest", Date(2023, 1, 1))));
         assert_eq!(
             date_value("20230101T120000Z"),
-            Ok(("T120000Z", "20230101".to_owned()))
+            Ok(("T120000Z", Date(2023, 1, 1)))
         );
 
         let result = date_value("");
@@ -50,18 +52,25 @@ mod tests {
         let result = date_value("ABCD0101");
         assert!(result.is_err());
 
-        // TODO
         let result = date_value("20231301");
-        assert_eq!(result, Ok(("", "20231301".to_owned())));
+        assert!(result.is_err());
 
-        // TODO
         let result = date_value("20230132");
-        assert_eq!(result, Ok(("", "20230132".to_owned())));
+        assert!(result.is_err());
+
+        let result = date_value("20230229");
+        assert!(result.is_err());
+
+        let result = date_value("19000229");
+        assert!(result.is_err());
+
+        let result = date_value("20230431");
+        assert!(result.is_err());
 
-        assert_eq!(date_value("20230101"), Ok(("", "20230101".to_owned())));
-        assert_eq!(date_value("20231201"), Ok(("", "20231201".to_owned())));
-        assert_eq!(date_value("20230401"), Ok(("", "20230401".to_owned())));
-        assert_eq!(date_value("20230430"), Ok(("", "20230430".to_owned())));
-        assert_eq!(date_value("20230131"), Ok(("", "20230131".to_owned())));
+        assert_eq!(date_value("20230101"), Ok(("", Date(2023, 1, 1))));
+        assert_eq!(date_value("20231201"), Ok(("", Date(2023, 12, 1))));
+        assert_eq!(date_value("20230401"), Ok(("", Date(2023, 4, 1))));
+        assert_eq!(date_value("20230430"), Ok(("", Date(2023, 4, 30))));
+        assert_eq!(date_value("20230131"), Ok(("", Date(2023, 1, 31))));
     }
 }
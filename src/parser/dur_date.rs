@@ -0,0 +1,30 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Duration,
+    parser::{dur_day, dur_time},
+};
+
+/// dur-date   = dur-day [dur-time]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_date(input: &str) -> IResult<&str, Duration> {
+    (dur_day, nom::combinator::opt(dur_time))
+        .map(|(days, seconds)| Duration(days, seconds.unwrap_or_default()))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_date() {
+        assert_eq!(dur_date("15D"), Ok(("", Duration(15, 0))));
+        assert_eq!(
+            dur_date("15DT5H0M20S"),
+            Ok(("", Duration(15, 5 * 3600 + 20)))
+        );
+        assert!(dur_date("T5H").is_err());
+    }
+}
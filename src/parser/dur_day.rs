@@ -0,0 +1,25 @@
+use nom::{IResult, Parser};
+
+/// dur-day    = 1*DIGIT "D"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_day(input: &str) -> IResult<&str, i64> {
+    nom::sequence::terminated(
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u32>),
+        nom::character::complete::char('D'),
+    )
+    .map(i64::from)
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_day() {
+        assert_eq!(dur_day("15D"), Ok(("", 15)));
+        assert!(dur_day("D").is_err());
+        assert!(dur_day("15W").is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use nom::{IResult, Parser};
+
+use crate::parser::dur_minute;
+
+/// dur-hour   = 1*DIGIT "H" [dur-minute]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_hour(input: &str) -> IResult<&str, i64> {
+    (
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u32>),
+        nom::character::complete::char('H'),
+        nom::combinator::opt(dur_minute),
+    )
+        .map(|(hours, _, seconds)| i64::from(hours) * 3600 + seconds.unwrap_or_default())
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_hour() {
+        assert_eq!(dur_hour("5H"), Ok(("", 5 * 3600)));
+        assert_eq!(dur_hour("5H0M20S"), Ok(("", 5 * 3600 + 20)));
+        // dur-second must follow dur-minute
+        assert_eq!(dur_hour("5H20S"), Ok(("20S", 5 * 3600)));
+        assert!(dur_hour("H").is_err());
+    }
+}
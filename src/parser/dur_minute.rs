@@ -0,0 +1,29 @@
+use nom::{IResult, Parser};
+
+use crate::parser::dur_second;
+
+/// dur-minute = 1*DIGIT "M" [dur-second]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_minute(input: &str) -> IResult<&str, i64> {
+    (
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u32>),
+        nom::character::complete::char('M'),
+        nom::combinator::opt(dur_second),
+    )
+        .map(|(minutes, _, seconds)| i64::from(minutes) * 60 + seconds.unwrap_or_default())
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_minute() {
+        assert_eq!(dur_minute("15M"), Ok(("", 15 * 60)));
+        assert_eq!(dur_minute("15M20S"), Ok(("", 15 * 60 + 20)));
+        assert!(dur_minute("M").is_err());
+        assert!(dur_minute("20S").is_err());
+    }
+}
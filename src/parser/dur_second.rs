@@ -0,0 +1,25 @@
+use nom::{IResult, Parser};
+
+/// dur-second = 1*DIGIT "S"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_second(input: &str) -> IResult<&str, i64> {
+    nom::sequence::terminated(
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u32>),
+        nom::character::complete::char('S'),
+    )
+    .map(i64::from)
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_second() {
+        assert_eq!(dur_second("20S"), Ok(("", 20)));
+        assert!(dur_second("S").is_err());
+        assert!(dur_second("20").is_err());
+    }
+}
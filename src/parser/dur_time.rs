@@ -0,0 +1,28 @@
+use nom::{IResult, Parser};
+
+use crate::parser::{dur_hour, dur_minute, dur_second};
+
+/// dur-time   = "T" (dur-hour / dur-minute / dur-second)
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_time(input: &str) -> IResult<&str, i64> {
+    nom::sequence::preceded(
+        nom::character::complete::char('T'),
+        nom::branch::alt((dur_hour, dur_minute, dur_second)),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_time() {
+        assert_eq!(dur_time("T5H0M20S"), Ok(("", 5 * 3600 + 20)));
+        assert_eq!(dur_time("T15M"), Ok(("", 15 * 60)));
+        assert_eq!(dur_time("T20S"), Ok(("", 20)));
+        assert!(dur_time("T").is_err());
+        assert!(dur_time("5H").is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Duration,
+    parser::{dur_date, dur_time, dur_week},
+};
+
+/// dur-value  = (["+"] / "-") "P" (dur-date / dur-time / dur-week)
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_value(input: &str) -> IResult<&str, Duration> {
+    (
+        nom::combinator::opt(nom::character::complete::one_of("+-")),
+        nom::character::complete::char('P'),
+        nom::branch::alt((
+            dur_date,
+            dur_time.map(|seconds| Duration(0, seconds)),
+            dur_week.map(Duration::weeks),
+        )),
+    )
+        .map(|(sign, _, duration)| match sign {
+            Some('-') => -duration,
+            _ => duration,
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_value() {
+        assert_eq!(
+            dur_value("P15DT5H0M20S"),
+            Ok(("", Duration(15, 5 * 3600 + 20)))
+        );
+        assert_eq!(dur_value("P7W"), Ok(("", Duration(49, 0))));
+        assert_eq!(dur_value("+PT15M"), Ok(("", Duration(0, 15 * 60))));
+        assert_eq!(dur_value("-P1D"), Ok(("", Duration(-1, 0))));
+        assert!(dur_value("P").is_err());
+        assert!(dur_value("PT").is_err());
+        assert!(dur_value("15D").is_err());
+        assert_eq!(dur_value("-PT1H30M"), Ok(("", Duration(0, -5400))));
+        assert_eq!(dur_value("P1W2D"), Ok(("2D", Duration(7, 0))));
+    }
+}
@@ -0,0 +1,25 @@
+use nom::{IResult, Parser};
+
+/// dur-week   = 1*DIGIT "W"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6>
+pub fn dur_week(input: &str) -> IResult<&str, i64> {
+    nom::sequence::terminated(
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u32>),
+        nom::character::complete::char('W'),
+    )
+    .map(i64::from)
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dur_week() {
+        assert_eq!(dur_week("7W"), Ok(("", 7)));
+        assert!(dur_week("W").is_err());
+        assert!(dur_week("7D").is_err());
+    }
+}
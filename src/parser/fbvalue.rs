@@ -0,0 +1,59 @@
+use nom::{IResult, Parser};
+
+use crate::{model::Period, parser::period};
+
+/// fbvalue    = period *("," period)
+/// ;Time value MUST be in the UTC time format.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.2.6>
+pub fn fbvalue(input: &str) -> IResult<&str, Vec<Period>> {
+    nom::multi::separated_list1(nom::character::complete::char(','), period).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, DateTime, Duration, Time, TimeForm};
+
+    use super::*;
+
+    #[test]
+    fn test_fbvalue() {
+        assert_eq!(
+            fbvalue("19970308T160000Z/PT8H30M"),
+            Ok((
+                "",
+                vec![Period::Start(
+                    DateTime(Date(1997, 3, 8), Time(16, 0, 0, TimeForm::Utc)),
+                    Duration(0, 30600)
+                )]
+            ))
+        );
+        assert_eq!(
+            fbvalue("19970308T160000Z/PT3H,19970308T200000Z/PT1H"),
+            Ok((
+                "",
+                vec![
+                    Period::Start(
+                        DateTime(Date(1997, 3, 8), Time(16, 0, 0, TimeForm::Utc)),
+                        Duration(0, 10800)
+                    ),
+                    Period::Start(
+                        DateTime(Date(1997, 3, 8), Time(20, 0, 0, TimeForm::Utc)),
+                        Duration(0, 3600)
+                    ),
+                ]
+            ))
+        );
+        assert_eq!(
+            fbvalue("19970308T160000Z/PT3H,"),
+            Ok((
+                ",",
+                vec![Period::Start(
+                    DateTime(Date(1997, 3, 8), Time(16, 0, 0, TimeForm::Utc)),
+                    Duration(0, 10800)
+                )]
+            ))
+        );
+        assert!(fbvalue("").is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use nom::{IResult, Parser};
+
+/// float      = (["+"] / "-") 1*DIGIT ["." 1*DIGIT]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.7>
+pub fn float(input: &str) -> IResult<&str, f64> {
+    nom::combinator::map_res(
+        nom::combinator::recognize((
+            nom::combinator::opt(nom::character::complete::one_of("+-")),
+            nom::character::complete::digit1,
+            nom::combinator::opt((
+                nom::character::complete::char('.'),
+                nom::character::complete::digit1,
+            )),
+        )),
+        str::parse,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float() {
+        assert_eq!(float("1000000.0000001"), Ok(("", 1000000.0000001)));
+        assert_eq!(float("1.333"), Ok(("", 1.333)));
+        assert_eq!(float("-2.5"), Ok(("", -2.5)));
+        assert_eq!(float("+3"), Ok(("", 3.0)));
+        assert_eq!(float("37.386013;"), Ok((";", 37.386013)));
+        assert_eq!(float("1."), Ok((".", 1.0)));
+        assert!(float("").is_err());
+        assert!(float(".5").is_err());
+        assert!(float("-").is_err());
+        assert_eq!(float("1e5"), Ok(("e5", 1.0)));
+    }
+}
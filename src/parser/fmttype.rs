@@ -0,0 +1,53 @@
+use nom::{IResult, Parser};
+
+use crate::model::MediaType;
+
+/// fmttypeparam = "FMTTYPE" "=" type-name "/" subtype-name
+///                ; Where "type-name" and "subtype-name" are
+///                ; defined in Section 4.2 of [RFC4288].
+///
+/// type-name = reg-name
+/// subtype-name = reg-name
+///
+/// reg-name = 1*127reg-name-chars
+/// reg-name-chars = ALPHA / DIGIT / "!" /
+///                 "#" / "$" / "&" / "." /
+///                 "+" / "-" / "^" / "_"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2.8>
+/// <https://datatracker.ietf.org/doc/html/rfc4288#section-4.2>
+pub fn fmttype(input: &str) -> IResult<&str, MediaType> {
+    nom::sequence::separated_pair(reg_name, nom::character::complete::char('/'), reg_name)
+        .map(|(type_name, subtype_name)| MediaType(type_name.to_owned(), subtype_name.to_owned()))
+        .parse(input)
+}
+
+fn reg_name(input: &str) -> IResult<&str, &str> {
+    nom::bytes::complete::take_while_m_n(1, 127, |c: char| {
+        c.is_ascii_alphanumeric() || "!#$&.+-^_".contains(c)
+    })
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmttype() {
+        assert_eq!(
+            fmttype("audio/basic"),
+            Ok(("", MediaType("audio".to_owned(), "basic".to_owned())))
+        );
+        assert_eq!(
+            fmttype("application/vnd.ms-excel"),
+            Ok((
+                "",
+                MediaType("application".to_owned(), "vnd.ms-excel".to_owned())
+            ))
+        );
+        assert!(fmttype("audio").is_err());
+        assert!(fmttype("/basic").is_err());
+        assert!(fmttype("audio/").is_err());
+    }
+}
@@ -0,0 +1,27 @@
+use nom::{IResult, Parser};
+
+use crate::parser::float;
+
+/// geovalue   = float ";" float
+/// ;Latitude and Longitude components
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.1.6>
+pub fn geovalue(input: &str) -> IResult<&str, (f64, f64)> {
+    nom::sequence::separated_pair(float, nom::character::complete::char(';'), float).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geovalue() {
+        assert_eq!(
+            geovalue("37.386013;-122.082932"),
+            Ok(("", (37.386013, -122.082932)))
+        );
+        assert_eq!(geovalue("0;0"), Ok(("", (0.0, 0.0))));
+        assert!(geovalue("37.386013").is_err());
+        assert!(geovalue("37.386013,-122.082932").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::{Property, PropertyValue},
+    parser::{iana_token, param, value},
+};
+
+/// iana-prop = iana-token *(";" icalparameter) ":" value CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.8.1>
+pub fn iana_prop(input: &str) -> IResult<&str, Property> {
+    (
+        iana_token,
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            param,
+        )),
+        nom::character::complete::char(':'),
+        value,
+        nom::character::complete::line_ending,
+    )
+        .map(|(name, params, _, value, _)| Property::new(name, params, PropertyValue::XType(value)))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::PropertyName;
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let input = "NON-SMOKING:TRUE\r\n";
+        assert_eq!(
+            iana_prop(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("NON-SMOKING"),
+                    Default::default(),
+                    PropertyValue::XType("TRUE".to_owned()),
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(iana_prop(":TRUE\r\n").is_err());
+        assert!(iana_prop("NON-SMOKING:TRUE").is_err());
+    }
+}
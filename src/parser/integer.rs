@@ -0,0 +1,35 @@
+use nom::{IResult, Parser};
+
+/// integer    = (["+"] / "-") 1*DIGIT
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.8>
+pub fn integer(input: &str) -> IResult<&str, i32> {
+    nom::combinator::map_res(
+        nom::combinator::recognize((
+            nom::combinator::opt(nom::character::complete::one_of("+-")),
+            nom::character::complete::digit1,
+        )),
+        str::parse,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer("1234567890"), Ok(("", 1234567890)));
+        assert_eq!(integer("-1234567890"), Ok(("", -1234567890)));
+        assert_eq!(integer("+1234567890"), Ok(("", 1234567890)));
+        assert_eq!(integer("432109876"), Ok(("", 432109876)));
+        assert_eq!(integer("0"), Ok(("", 0)));
+        assert_eq!(integer("12;"), Ok((";", 12)));
+        assert!(integer("").is_err());
+        assert!(integer("+").is_err());
+        assert!(integer("a1").is_err());
+        assert!(integer("2147483648").is_err());
+        assert!(integer("-2147483649").is_err());
+    }
+}
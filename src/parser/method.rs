@@ -0,0 +1,55 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Property,
+    parser::{metvalue, other_param},
+};
+
+/// method     = "METHOD" metparam ":" metvalue CRLF
+///
+/// metparam   = *(";" other-param)
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.2>
+pub fn method(input: &str) -> IResult<&str, Property> {
+    (
+        nom::bytes::complete::tag_no_case("METHOD"),
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            other_param,
+        )),
+        nom::character::complete::char(':'),
+        metvalue,
+        nom::character::complete::line_ending,
+    )
+        .map(|(name, metparam, _, metvalue, _)| Property::new(name.to_owned(), metparam, metvalue))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{PropertyName, PropertyValue, Text};
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let input = "METHOD:REQUEST\r\n";
+        assert_eq!(
+            method(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("METHOD"),
+                    Default::default(),
+                    PropertyValue::Text(Text("REQUEST".to_owned())),
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(method("METHOD:\r\n").is_err());
+        assert!(method("METHOD:REQUEST REPLY\r\n").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::{PropertyValue, Text},
+    parser::iana_token,
+};
+
+/// metvalue   = iana-token
+///
+/// The value is case-insensitive, so it is kept in uppercase.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.2>
+pub fn metvalue(input: &str) -> IResult<&str, PropertyValue> {
+    iana_token
+        .map(|s| PropertyValue::Text(Text(s.to_ascii_uppercase())))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metvalue() {
+        assert_eq!(
+            metvalue("PUBLISH"),
+            Ok(("", PropertyValue::Text(Text("PUBLISH".to_owned()))))
+        );
+        assert_eq!(
+            metvalue("Publish"),
+            Ok(("", PropertyValue::Text(Text("PUBLISH".to_owned()))))
+        );
+        assert!(metvalue("").is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use nom::{IResult, Parser};
+
+use crate::parser::param;
+
+/// other-param   = (iana-param / x-param)
+///
+/// iana-param  = iana-token "=" param-value *("," param-value)
+/// ; Some other IANA-registered iCalendar parameter.
+///
+/// x-param     = x-name "=" param-value *("," param-value)
+/// ; A non-standard, experimental parameter.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.2>
+pub fn other_param(input: &str) -> IResult<&str, (String, Vec<String>)> {
+    // param-name = iana-token / x-name
+    param.parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_other_param() {
+        assert_eq!(
+            other_param("X-ABC-PARAM=a,b"),
+            Ok((
+                "",
+                (
+                    "X-ABC-PARAM".to_owned(),
+                    vec!["a".to_owned(), "b".to_owned()]
+                )
+            ))
+        );
+        assert_eq!(
+            other_param("LANGUAGE=en"),
+            Ok(("", ("LANGUAGE".to_owned(), vec!["en".to_owned()])))
+        );
+        assert!(other_param(":value").is_err());
+    }
+}
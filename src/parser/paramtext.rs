@@ -1,12 +1,15 @@
 use nom::{IResult, Parser};
 
-use crate::parser::safe_char;
+use crate::parser::{caret_escape, safe_char};
 
 /// paramtext     = *SAFE-CHAR
 ///
+/// The caret encoding is decoded, see [`caret_escape`].
+///
 /// <https://datatracker.ietf.org/doc/html/rfc5545>
+/// <https://datatracker.ietf.org/doc/html/rfc6868>
 pub fn paramtext(input: &str) -> IResult<&str, String> {
-    nom::multi::many0(safe_char)
+    nom::multi::many0(nom::branch::alt((caret_escape, safe_char)))
         .map(|chars| chars.iter().collect::<String>())
         .parse(input)
 }
@@ -22,5 +25,10 @@ mod tests {
         assert_eq!(paramtext("value123"), Ok(("", "value123".to_string())));
         assert_eq!(paramtext(""), Ok(("", "".to_string())));
         assert_eq!(paramtext(";invalid"), Ok((";invalid", "".to_string())));
+
+        // caret encoding
+        assert_eq!(paramtext("a^nb"), Ok(("", "a\nb".to_string())));
+        assert_eq!(paramtext("a^^b^'c"), Ok(("", "a^b\"c".to_string())));
+        assert_eq!(paramtext("a^b"), Ok(("", "a^b".to_string())));
     }
 }
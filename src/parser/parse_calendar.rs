@@ -0,0 +1,1038 @@
+use std::ops::Range;
+
+use nom::Parser;
+
+use crate::{
+    error::{Diagnostic, ParseError, ParseErrorKind, Severity, ValidationError},
+    model::{CalendarObject, Component, ComponentName, Property, PropertyName, PropertyValue},
+    parser::{
+        LogicalLine, calprop, contentline, name, param_name, param_value, property, unfold, value,
+    },
+};
+
+/// icalobject = "BEGIN" ":" "VCALENDAR" CRLF
+///              icalbody
+///              "END" ":" "VCALENDAR" CRLF
+///
+/// component  = 1*(eventc / todoc / journalc / freebusyc /
+///              timezonec / iana-comp / x-comp)
+///
+/// iana-comp  = "BEGIN" ":" iana-token CRLF
+///              1*contentline
+///              "END" ":" iana-token CRLF
+///
+/// x-comp     = "BEGIN" ":" x-name CRLF
+///              1*contentline
+///              "END" ":" x-name CRLF
+///
+/// The input is unfolded first, then each content line is parsed.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.4>
+pub fn parse_calendar(input: &str) -> Result<CalendarObject, ParseError> {
+    parse(input, &mut |error, _| Err(error))
+}
+
+/// Parses like [`parse_calendar`], but recovers from the errors instead of
+/// stopping at the first one, and returns the best-effort calendar object
+/// along with a diagnostic for each error.
+///
+/// - A line that is not a content line is kept as a property named by the
+///   text before the first ";" or ":", without parameters, and with the
+///   text after the first ":" as the value ([`Severity::Warning`]).
+/// - A property with an invalid value is kept with the value as it is
+///   ([`Severity::Warning`]).
+/// - A component that does not follow the rules for its properties is kept
+///   as it is ([`Severity::Warning`]).
+/// - An END that does not match the innermost BEGIN ends the components
+///   up to the matching one, or is skipped if there is none
+///   ([`Severity::Error`]).
+/// - A component without END is ended at the end of the input
+///   ([`Severity::Error`]).
+/// - The lines outside of the calendar are skipped ([`Severity::Error`]).
+pub fn parse_calendar_lenient(input: &str) -> (CalendarObject, Vec<Diagnostic>) {
+    let mut diagnostics = vec![];
+    let result = parse(input, &mut |error, severity| {
+        diagnostics.push(Diagnostic::new(severity, error));
+        Ok(())
+    });
+    let calendar = match result {
+        Ok(calendar) => calendar,
+        Err(error) => {
+            diagnostics.push(Diagnostic::new(Severity::Error, error));
+            CalendarObject(vec![], vec![])
+        }
+    };
+    (calendar, diagnostics)
+}
+
+/// A component being parsed: the lines of its BEGIN, the component, and the
+/// lines of each of its properties along with whether its value has been
+/// reported as invalid.
+type OpenComponent = (Range<usize>, Component, Vec<(Range<usize>, bool)>);
+
+/// Reports an error, and returns it to stop the parsing or `Ok(())` to
+/// parse on.
+type Report<'a> = dyn FnMut(ParseError, Severity) -> Result<(), ParseError> + 'a;
+
+fn parse(input: &str, report: &mut Report) -> Result<CalendarObject, ParseError> {
+    let mut stack: Vec<OpenComponent> = vec![];
+    let mut calendar = None;
+    let mut end = 1;
+    for line in unfold(input) {
+        let lines = line.lines();
+        end = lines.end;
+        if calendar.is_some() {
+            report(
+                ParseError::new(ParseErrorKind::ExpectedEndOfInput, lines)
+                    .with_mismatch("end of input", quote(content(&line))),
+                Severity::Error,
+            )?;
+            break;
+        }
+
+        let parent = stack.last().map(|(_, component, _)| component.0.clone());
+        let (name, params, value) = match contentline(line.as_str()) {
+            Ok((_, contentline)) => contentline,
+            Err(_) => {
+                let error = invalid_content_line(&line, parent);
+                match stack.last_mut() {
+                    Some((_, component, property_lines)) => {
+                        report(error, Severity::Warning)?;
+                        component.1.push(raw_property(&line));
+                        property_lines.push((lines, true));
+                    }
+                    None => report(error, Severity::Error)?,
+                }
+                continue;
+            }
+        };
+        match name.to_ascii_uppercase().as_str() {
+            "BEGIN" => {
+                let component_name = match component_name(&line, &value, parent) {
+                    Ok(component_name) => component_name,
+                    Err(error) => {
+                        report(error, Severity::Warning)?;
+                        ComponentName::new(&value)
+                    }
+                };
+                if stack.is_empty() && component_name.as_str() != "VCALENDAR" {
+                    report(expected_calendar(&line), Severity::Error)?;
+                    continue;
+                }
+                stack.push((lines, Component(component_name, vec![], vec![]), vec![]));
+            }
+            "END" => {
+                let component_name = match component_name(&line, &value, parent) {
+                    Ok(component_name) => component_name,
+                    Err(error) => {
+                        report(error, Severity::Warning)?;
+                        ComponentName::new(&value)
+                    }
+                };
+                let Some((_, component, _)) = stack.last() else {
+                    report(expected_calendar(&line), Severity::Error)?;
+                    continue;
+                };
+                if component.0 != component_name {
+                    let expected = format!("END:{}", component.0.as_str());
+                    report(
+                        ParseError::new(
+                            ParseErrorKind::MismatchedEnd {
+                                expected: component.0.clone(),
+                                found: component_name.clone(),
+                            },
+                            lines.clone(),
+                        )
+                        .with_component(Some(component.0.clone()))
+                        .with_mismatch(expected, quote(content(&line))),
+                        Severity::Error,
+                    )?;
+                    let Some(index) = stack
+                        .iter()
+                        .rposition(|(_, component, _)| component.0 == component_name)
+                    else {
+                        continue;
+                    };
+                    // the components inside the matching one end before this line
+                    while stack.len() > index + 1 {
+                        end_component(&mut stack, lines.start, &mut calendar, report)?;
+                    }
+                }
+                end_component(&mut stack, lines.end, &mut calendar, report)?;
+            }
+            _ => {
+                let typed = match stack.as_slice() {
+                    [] => {
+                        report(expected_calendar(&line), Severity::Error)?;
+                        continue;
+                    }
+                    [_] => calprop(line.as_str()),
+                    _ => property(line.as_str()),
+                };
+                let (property, reported) = match typed {
+                    Ok((_, property)) => (property, false),
+                    Err(_) => {
+                        report(
+                            invalid_property_value(&line, &name, &value, parent),
+                            Severity::Warning,
+                        )?;
+                        let value = PropertyValue::XType(value);
+                        (Property::new(name, params, value), true)
+                    }
+                };
+                if let Some((_, component, property_lines)) = stack.last_mut() {
+                    component.1.push(property);
+                    property_lines.push((lines, reported));
+                }
+            }
+        }
+    }
+
+    while let Some((lines, component, _)) = stack.last() {
+        let expected = format!("END:{}", component.0.as_str());
+        report(
+            ParseError::new(
+                ParseErrorKind::UnterminatedComponent(component.0.clone()),
+                lines.clone(),
+            )
+            .with_component(Some(component.0.clone()))
+            .with_mismatch(expected, "end of input"),
+            Severity::Error,
+        )?;
+        end_component(&mut stack, end, &mut calendar, report)?;
+    }
+
+    match calendar {
+        Some(calendar) => Ok(calendar),
+        None => {
+            report(
+                ParseError::new(ParseErrorKind::ExpectedCalendar, 1..1)
+                    .with_mismatch("BEGIN:VCALENDAR", "end of input"),
+                Severity::Error,
+            )?;
+            Ok(CalendarObject(vec![], vec![]))
+        }
+    }
+}
+
+/// Ends the innermost component before the physical line `end`, and adds
+/// it to its parent, or makes it the calendar object if it is the
+/// outermost one.
+fn end_component(
+    stack: &mut Vec<OpenComponent>,
+    end: usize,
+    calendar: &mut Option<CalendarObject>,
+    report: &mut Report,
+) -> Result<(), ParseError> {
+    let Some((begin, component, property_lines)) = stack.pop() else {
+        return Ok(());
+    };
+    let lines = begin.start..end;
+    // a violation about a property that occurs is found at the property,
+    // and is not reported again if it is the invalid value reported already
+    let validation_error = |e: ValidationError, name: &ComponentName, properties: &[Property]| {
+        let violating = violating_property(&e, properties, &property_lines);
+        if let (ValidationError::InvalidPropertyValue(_), Some((_, true))) = (&e, &violating) {
+            return None;
+        }
+        let lines = violating.map_or_else(|| lines.clone(), |(lines, _)| lines);
+        let property = e.property().cloned();
+        Some(
+            ParseError::new(ParseErrorKind::Validation(e), lines)
+                .with_component(Some(name.clone()))
+                .with_property(property),
+        )
+    };
+    match stack.last_mut() {
+        Some((_, parent, _)) => {
+            let error = validate_without_invalid_components(&component)
+                .err()
+                .and_then(|e| validation_error(e, &component.0, &component.1));
+            if let Some(error) = error {
+                report(error, Severity::Warning)?;
+            }
+            parent.2.push(component);
+        }
+        None => {
+            let calendar_object = CalendarObject(component.1, component.2);
+            let error = calendar_object
+                .validate()
+                .err()
+                .and_then(|e| validation_error(e, &component.0, &calendar_object.0));
+            if let Some(error) = error {
+                report(error, Severity::Warning)?;
+            }
+            *calendar = Some(calendar_object);
+        }
+    }
+    Ok(())
+}
+
+/// Finds the lines of the property the violation is about, and whether its
+/// value has been reported as invalid: the second occurrence of a duplicate
+/// property, or else the first occurrence.
+fn violating_property(
+    e: &ValidationError,
+    properties: &[Property],
+    property_lines: &[(Range<usize>, bool)],
+) -> Option<(Range<usize>, bool)> {
+    let name = e.property()?;
+    let index = match e {
+        ValidationError::DuplicateProperty(_) => 1,
+        _ => 0,
+    };
+    properties
+        .iter()
+        .zip(property_lines)
+        .filter(|(property, _)| property.name() == name)
+        .nth(index)
+        .map(|(_, property_lines)| property_lines.clone())
+}
+
+/// Validates the component as if the child components that are invalid,
+/// which are reported as they end, were not there, so that their errors are
+/// not reported again for the component.
+fn validate_without_invalid_components(component: &Component) -> Result<(), ValidationError> {
+    if component.2.iter().all(|child| child.validate().is_ok()) {
+        return component.validate();
+    }
+    Component(
+        component.0.clone(),
+        component.1.clone(),
+        component
+            .2
+            .iter()
+            .filter(|child| child.validate().is_ok())
+            .cloned()
+            .collect(),
+    )
+    .validate()
+}
+
+fn invalid_property_value(
+    line: &LogicalLine,
+    name: &str,
+    value: &str,
+    parent: Option<ComponentName>,
+) -> ParseError {
+    let property_name = PropertyName::new(name);
+    ParseError::new(ParseErrorKind::InvalidPropertyValue, line.lines())
+        .with_position(line.position(value_offset(line, value)))
+        .with_component(parent)
+        .with_mismatch(
+            format!("a valid {} value", property_name.as_str()),
+            quote(value),
+        )
+        .with_property(Some(property_name))
+}
+
+fn expected_calendar(line: &LogicalLine) -> ParseError {
+    ParseError::new(ParseErrorKind::ExpectedCalendar, line.lines())
+        .with_mismatch("BEGIN:VCALENDAR", quote(content(line)))
+}
+
+/// Keeps a line that is not a content line as a property, see
+/// [`parse_calendar_lenient`].
+fn raw_property(line: &LogicalLine) -> Property {
+    let content = content(line);
+    let name = content.find([';', ':']).map_or(content, |i| &content[..i]);
+    let value = content.find(':').map_or("", |i| &content[i + 1..]);
+    Property::new(
+        name.to_owned(),
+        vec![],
+        PropertyValue::XType(value.to_owned()),
+    )
+}
+
+fn component_name(
+    line: &LogicalLine,
+    value: &str,
+    parent: Option<ComponentName>,
+) -> Result<ComponentName, ParseError> {
+    nom::combinator::all_consuming(name)
+        .parse(value)
+        .map(|(_, name)| ComponentName::new(&name))
+        .map_err(|_| {
+            ParseError::new(ParseErrorKind::InvalidComponentName, line.lines())
+                .with_position(line.position(value_offset(line, value)))
+                .with_component(parent)
+                .with_mismatch("a component name", quote(value))
+        })
+}
+
+/// Finds where and why the line is not a content line, by parsing it again
+/// part by part.
+fn invalid_content_line(line: &LogicalLine, parent: Option<ComponentName>) -> ParseError {
+    let text = line.as_str();
+    let offset = |rest: &str| text.len() - rest.len();
+    let error = |rest: &str, property: Option<String>, expected: &str| {
+        let found = match rest.chars().next() {
+            None | Some('\r' | '\n') => "end of line".to_owned(),
+            Some(c) => format!("{:?}", c),
+        };
+        ParseError::new(ParseErrorKind::InvalidContentLine, line.lines())
+            .with_position(line.position(offset(rest)))
+            .with_component(parent.clone())
+            .with_property(property.map(|name| PropertyName::new(&name)))
+            .with_mismatch(expected, found)
+    };
+
+    let Ok((mut rest, property)) = name(text) else {
+        return error(text, None, "a property name");
+    };
+    let property = Some(property);
+    let mut expected = "\";\" or \":\"";
+    while let Some(params) = rest.strip_prefix(';') {
+        let Ok((params, _)) = param_name(params) else {
+            return error(params, property, "a parameter name");
+        };
+        let Some(mut params) = params.strip_prefix('=') else {
+            return error(params, property, "\"=\"");
+        };
+        loop {
+            let Ok((values, _)) = param_value(params) else {
+                return error(params, property, "a parameter value");
+            };
+            match values.strip_prefix(',') {
+                Some(values) => params = values,
+                None => {
+                    rest = values;
+                    expected = "\",\", \";\" or \":\"";
+                    break;
+                }
+            }
+        }
+    }
+    let Some(values) = rest.strip_prefix(':') else {
+        return error(rest, property, expected);
+    };
+    match value(values) {
+        Ok((rest, _)) => error(rest, property, "a value character or a line break"),
+        Err(_) => error(values, property, "a value"),
+    }
+}
+
+/// Returns the offset of the value in the unfolded text of the line.
+fn value_offset(line: &LogicalLine, value: &str) -> usize {
+    content(line).len() - value.len()
+}
+
+/// Returns the unfolded text of the line without the line break.
+fn content(line: &LogicalLine) -> &str {
+    line.as_str().trim_end_matches(['\r', '\n'])
+}
+
+fn quote(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Date, DateTime, Duration, ParameterName, ParameterValue, PropertyName, Text, Time,
+        TimeForm,
+        fixtures::{property, text_property},
+    };
+
+    use super::*;
+
+    fn error(input: &str) -> Option<(ParseErrorKind, Range<usize>)> {
+        parse_calendar(input)
+            .err()
+            .map(|error| (error.0.kind, error.0.lines))
+    }
+
+    fn typed(name: &str, value: PropertyValue) -> Property {
+        Property(PropertyName::new(name), Default::default(), value)
+    }
+
+    fn dtstamp() -> Property {
+        typed(
+            "DTSTAMP",
+            PropertyValue::DateTime(DateTime(Date(1997, 6, 10), Time(17, 23, 45, TimeForm::Utc))),
+        )
+    }
+
+    #[test]
+    fn test_parse_calendar() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//Example Corp//NONSGML Example//EN\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VEVENT\r\n",
+            "UID:19970610T172345Z-AF23B2@example.com\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "SUMMARY:Bastille Day\r\n",
+            "  Party\r\n",
+            "BEGIN:VALARM\r\n",
+            "TRIGGER:-PT15M\r\n",
+            "ACTION:DISPLAY\r\n",
+            "DESCRIPTION:Reminder\r\n",
+            "END:VALARM\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:X-ABC-COMP\r\n",
+            "X-ABC-PROP;X-ABC-PARAM=a,b:value\r\n",
+            "END:X-ABC-COMP\r\n",
+            "BEGIN:VAVAILABILITY\r\n",
+            "UID:1\r\n",
+            "END:VAVAILABILITY\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            parse_calendar(&input),
+            Ok(CalendarObject(
+                vec![
+                    Property(
+                        PropertyName::new("PRODID"),
+                        Default::default(),
+                        PropertyValue::Text(Text(
+                            "-//Example Corp//NONSGML Example//EN".to_owned()
+                        )),
+                    ),
+                    Property(
+                        PropertyName::new("VERSION"),
+                        Default::default(),
+                        PropertyValue::Text(Text("2.0".to_owned())),
+                    ),
+                ],
+                vec![
+                    Component(
+                        ComponentName::new("VEVENT"),
+                        vec![
+                            text_property("UID", "19970610T172345Z-AF23B2@example.com"),
+                            dtstamp(),
+                            text_property("SUMMARY", "Bastille Day Party"),
+                        ],
+                        vec![Component(
+                            ComponentName::new("VALARM"),
+                            vec![
+                                typed("TRIGGER", PropertyValue::Duration(Duration(0, -15 * 60))),
+                                text_property("ACTION", "DISPLAY"),
+                                text_property("DESCRIPTION", "Reminder"),
+                            ],
+                            vec![],
+                        )],
+                    ),
+                    Component(
+                        ComponentName::new("X-ABC-COMP"),
+                        vec![Property(
+                            PropertyName::new("X-ABC-PROP"),
+                            [(
+                                ParameterName::new("X-ABC-PARAM"),
+                                ParameterValue(vec!["a".to_owned(), "b".to_owned()]),
+                            )]
+                            .into_iter()
+                            .collect(),
+                            PropertyValue::XType("value".to_owned()),
+                        )],
+                        vec![],
+                    ),
+                    Component(
+                        ComponentName::new("VAVAILABILITY"),
+                        vec![text_property("UID", "1")],
+                        vec![],
+                    ),
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_calendar_types_component_properties() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VTIMEZONE\r\n",
+            "TZID:America/New_York\r\n",
+            "BEGIN:STANDARD\r\n",
+            "DTSTART:19671029T020000\r\n",
+            "TZOFFSETFROM:-0400\r\n",
+            "TZOFFSETTO:-0500\r\n",
+            "END:STANDARD\r\n",
+            "END:VTIMEZONE\r\n",
+            "BEGIN:VTODO\r\n",
+            "UID:1\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "DUE;TZID=America/New_York:19970615T090000\r\n",
+            "PRIORITY:1\r\n",
+            "STATUS:NEEDS-ACTION\r\n",
+            "CATEGORIES:FAMILY,FINANCE\r\n",
+            "END:VTODO\r\n",
+            "BEGIN:VFREEBUSY\r\n",
+            "UID:2\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "FREEBUSY:19980314T233000Z/19980315T003000Z\r\n",
+            "END:VFREEBUSY\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        let Ok(calendar) = parse_calendar(&input) else {
+            panic!("the calendar is invalid");
+        };
+        let todo = &calendar.components()[1];
+        assert_eq!(
+            todo.properties()[3],
+            typed("PRIORITY", PropertyValue::Integer(1))
+        );
+        assert_eq!(
+            todo.properties()[5],
+            typed(
+                "CATEGORIES",
+                PropertyValue::List(vec![
+                    PropertyValue::Text(Text("FAMILY".to_owned())),
+                    PropertyValue::Text(Text("FINANCE".to_owned())),
+                ])
+            )
+        );
+        assert!(calendar.time_zone("America/New_York").is_some());
+        assert_eq!(calendar.todos().filter(Result::is_ok).count(), 1);
+        assert_eq!(calendar.free_busys().filter(Result::is_ok).count(), 1);
+    }
+
+    #[test]
+    fn test_case_insensitive_names() {
+        let input = [
+            "begin:vcalendar\r\n",
+            "prodid:-//ABC//EN\r\n",
+            "Version:2.0\r\n",
+            "Begin:vEvent\r\n",
+            "uid:1\r\n",
+            "dtstamp:19970610T172345Z\r\n",
+            "END:VEVENT\r\n",
+            "end:VCALENDAR\r\n",
+        ]
+        .concat();
+        let Ok(calendar) = parse_calendar(&input) else {
+            panic!("VCALENDAR is invalid");
+        };
+        assert_eq!(calendar.0[0].name(), &PropertyName::new("PRODID"));
+        assert_eq!(calendar.0[0].name().original(), "prodid");
+        assert_eq!(calendar.0[1].name(), &PropertyName::new("VERSION"));
+        assert_eq!(calendar.1[0].0, ComponentName::new("VEVENT"));
+        assert_eq!(calendar.1[0].0.original(), "vEvent");
+        assert_eq!(calendar.1[0].1, vec![text_property("UID", "1"), dtstamp()]);
+    }
+
+    #[test]
+    fn test_mismatched_end() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "BEGIN:VEVENT\r\n",
+            "END:VTODO\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::MismatchedEnd {
+                    expected: ComponentName::new("VEVENT"),
+                    found: ComponentName::new("VTODO"),
+                },
+                3..4
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unterminated_component() {
+        let input = ["BEGIN:VCALENDAR\r\n", "BEGIN:X-COMP\r\n", "END:X-COMP\r\n"].concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::UnterminatedComponent(ComponentName::new("VCALENDAR")),
+                1..2
+            ))
+        );
+    }
+
+    #[test]
+    fn test_expected_calendar() {
+        assert_eq!(error(""), Some((ParseErrorKind::ExpectedCalendar, 1..1)));
+        assert_eq!(
+            error("BEGIN:VEVENT\r\nEND:VEVENT\r\n"),
+            Some((ParseErrorKind::ExpectedCalendar, 1..2))
+        );
+        assert_eq!(
+            error("PRODID:-//ABC//EN\r\n"),
+            Some((ParseErrorKind::ExpectedCalendar, 1..2))
+        );
+    }
+
+    #[test]
+    fn test_expected_end_of_input() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "END:VCALENDAR\r\n",
+            "BEGIN:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((ParseErrorKind::ExpectedEndOfInput, 5..6))
+        );
+    }
+
+    #[test]
+    fn test_calprops_cardinality() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::Validation(ValidationError::MissingProperty(PropertyName::new(
+                    "VERSION"
+                ))),
+                1..4
+            ))
+        );
+
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::Validation(ValidationError::DuplicateProperty(PropertyName::new(
+                    "PRODID"
+                ))),
+                4..5
+            ))
+        );
+
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "METHOD:PUBLISH\r\n",
+            "METHOD:REQUEST\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::Validation(ValidationError::DuplicateProperty(PropertyName::new(
+                    "METHOD"
+                ))),
+                5..6
+            ))
+        );
+
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:3.0;\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((ParseErrorKind::InvalidPropertyValue, 3..4))
+        );
+    }
+
+    #[test]
+    fn test_invalid_lines() {
+        let input = ["BEGIN:VCALENDAR\r\n", "INVALID\r\n", "END:VCALENDAR\r\n"].concat();
+        assert_eq!(
+            error(&input),
+            Some((ParseErrorKind::InvalidContentLine, 2..3))
+        );
+
+        let input = ["BEGIN:VCALENDAR\r\n", "BEGIN:V EVENT\r\n"].concat();
+        assert_eq!(
+            error(&input),
+            Some((ParseErrorKind::InvalidComponentName, 2..3))
+        );
+    }
+
+    #[test]
+    fn test_component_validation() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VEVENT\r\n",
+            "UID:1\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "DTSTART:19970714T170000Z\r\n",
+            "DTEND:19970715T035959Z\r\n",
+            "DURATION:PT1H\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        assert_eq!(
+            error(&input),
+            Some((
+                ParseErrorKind::Validation(ValidationError::MutuallyExclusiveProperties(
+                    PropertyName::new("DTEND"),
+                    PropertyName::new("DURATION")
+                )),
+                8..9
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validation_error_position() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VTODO\r\n",
+            "UID:1\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "SUMMARY:a long\r\n",
+            "  summary\r\n",
+            "PRIORITY:10\r\n",
+            "END:VTODO\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        let Err(error) = parse_calendar(&input) else {
+            panic!("VCALENDAR is valid");
+        };
+        assert_eq!(
+            error.kind(),
+            &ParseErrorKind::Validation(ValidationError::PropertyValueOutOfRange(
+                PropertyName::new("PRIORITY")
+            ))
+        );
+        assert_eq!(error.lines(), 9..10);
+        assert_eq!((error.line(), error.column()), (9, 1));
+        assert_eq!(error.component(), Some(&ComponentName::new("VTODO")));
+        assert_eq!(
+            error.to_string(),
+            "line 9, column 1: value of property PRIORITY is out of range in component VTODO"
+        );
+    }
+
+    #[test]
+    fn test_error_position_and_message() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY;LANG\r\n",
+            " UAGE:x\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        let Err(error) = parse_calendar(&input) else {
+            panic!("VCALENDAR is valid");
+        };
+        assert_eq!(error.kind(), &ParseErrorKind::InvalidContentLine);
+        assert_eq!(error.lines(), 5..7);
+        assert_eq!((error.line(), error.column()), (6, 6));
+        assert_eq!(error.component(), Some(&ComponentName::new("VEVENT")));
+        assert_eq!(error.property(), Some(&PropertyName::new("SUMMARY")));
+        assert_eq!(error.expected(), Some("\"=\""));
+        assert_eq!(error.found(), Some("':'"));
+        assert_eq!(
+            error.to_string(),
+            "line 6, column 6: expected \"=\", found ':' in property SUMMARY in component VEVENT"
+        );
+
+        let input = ["BEGIN:VCALENDAR\r\n", "VERSION:3.0;\r\n"].concat();
+        assert_eq!(
+            parse_calendar(&input).map_err(|error| error.to_string()),
+            Err("line 2, column 9: expected a valid VERSION value, found \"3.0;\" in property VERSION in component VCALENDAR".to_owned())
+        );
+
+        let input = ["BEGIN:VCALENDAR\r\n", "X-A B:c\r\n"].concat();
+        assert_eq!(
+            parse_calendar(&input).map_err(|error| error.to_string()),
+            Err("line 2, column 4: expected \";\" or \":\", found ' ' in property X-A in component VCALENDAR".to_owned())
+        );
+
+        let input = ["BEGIN:VCALENDAR\r\n", "BEGIN:VEVENT\r\n"].concat();
+        assert_eq!(
+            parse_calendar(&input).map_err(|error| error.to_string()),
+            Err(
+                "line 2, column 1: expected END:VEVENT, found end of input in component VEVENT"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_error_source() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "END:VCALENDAR\r\n",
+        ]
+        .concat();
+        let Err(error) = parse_calendar(&input) else {
+            panic!("VCALENDAR is valid");
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: missing required property VERSION in component VCALENDAR"
+        );
+        assert_eq!(error.property(), Some(&PropertyName::new("VERSION")));
+        let source = std::error::Error::source(&error).map(|e| e.to_string());
+        assert_eq!(source, Some("missing required property VERSION".to_owned()));
+    }
+
+    #[test]
+    fn test_parse_calendar_lenient() {
+        let input = [
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0\r\n",
+            "X-BROKEN\r\n",
+            "BEGIN:VEVENT\r\n",
+            "UID:1\r\n",
+            "DTSTAMP:19970610T172345Z\r\n",
+            "SUMMARY;LANG:Party\r\n",
+            "DTSTART:garbage\r\n",
+            "BEGIN:VALARM\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+            "trailing\r\n",
+        ]
+        .concat();
+        let (calendar, diagnostics) = parse_calendar_lenient(&input);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.severity(), d.error().kind(), d.error().line()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Severity::Warning, &ParseErrorKind::InvalidContentLine, 4),
+                (Severity::Warning, &ParseErrorKind::InvalidContentLine, 8),
+                (Severity::Warning, &ParseErrorKind::InvalidPropertyValue, 9),
+                (
+                    Severity::Error,
+                    &ParseErrorKind::MismatchedEnd {
+                        expected: ComponentName::new("VALARM"),
+                        found: ComponentName::new("VEVENT"),
+                    },
+                    11
+                ),
+                (
+                    Severity::Warning,
+                    &ParseErrorKind::Validation(ValidationError::MissingProperty(
+                        PropertyName::new("ACTION")
+                    )),
+                    10
+                ),
+                (Severity::Error, &ParseErrorKind::ExpectedEndOfInput, 13),
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "warning: line 8, column 13: expected \"=\", found ':' in property SUMMARY in component VEVENT"
+        );
+        assert_eq!(
+            diagnostics[2].to_string(),
+            "warning: line 9, column 9: expected a valid DTSTART value, found \"garbage\" in property DTSTART in component VEVENT"
+        );
+        assert_eq!(
+            diagnostics[4].to_string(),
+            "warning: line 10, column 1: missing required property ACTION in component VALARM"
+        );
+        assert_eq!(calendar.properties()[2], property("X-BROKEN", ""));
+        assert_eq!(
+            calendar.components(),
+            &[Component(
+                ComponentName::new("VEVENT"),
+                vec![
+                    text_property("UID", "1"),
+                    dtstamp(),
+                    property("SUMMARY", "Party"),
+                    property("DTSTART", "garbage"),
+                ],
+                vec![Component(ComponentName::new("VALARM"), vec![], vec![])],
+            )]
+        );
+        // the VEVENT is kept, but it is not a valid one
+        assert_eq!(
+            calendar.events().collect::<Vec<_>>(),
+            vec![Err(ValidationError::MissingProperty(PropertyName::new(
+                "ACTION"
+            )))]
+        );
+    }
+
+    #[test]
+    fn test_parse_calendar_lenient_unterminated() {
+        let input = [
+            "junk\r\n",
+            "BEGIN:VCALENDAR\r\n",
+            "PRODID:-//ABC//EN\r\n",
+            "VERSION:2.0;\r\n",
+            "BEGIN:X-COMP\r\n",
+            "END:X-OTHER\r\n",
+            "X-A:1\r\n",
+        ]
+        .concat();
+        let (calendar, diagnostics) = parse_calendar_lenient(&input);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.severity(), d.error().kind(), d.error().line()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Severity::Error, &ParseErrorKind::InvalidContentLine, 1),
+                (Severity::Warning, &ParseErrorKind::InvalidPropertyValue, 4),
+                (
+                    Severity::Error,
+                    &ParseErrorKind::MismatchedEnd {
+                        expected: ComponentName::new("X-COMP"),
+                        found: ComponentName::new("X-OTHER"),
+                    },
+                    6
+                ),
+                (
+                    Severity::Error,
+                    &ParseErrorKind::UnterminatedComponent(ComponentName::new("X-COMP")),
+                    5
+                ),
+                (
+                    Severity::Error,
+                    &ParseErrorKind::UnterminatedComponent(ComponentName::new("VCALENDAR")),
+                    2
+                ),
+            ]
+        );
+        assert_eq!(calendar.properties()[1], property("VERSION", "2.0;"));
+        assert_eq!(
+            calendar.components(),
+            &[Component(
+                ComponentName::new("X-COMP"),
+                vec![property("X-A", "1")],
+                vec![],
+            )]
+        );
+
+        let (calendar, diagnostics) = parse_calendar_lenient("");
+        assert_eq!(calendar, CalendarObject(vec![], vec![]));
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::new(
+                Severity::Error,
+                ParseError::new(ParseErrorKind::ExpectedCalendar, 1..1)
+                    .with_mismatch("BEGIN:VCALENDAR", "end of input")
+            )]
+        );
+    }
+}
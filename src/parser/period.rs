@@ -0,0 +1,46 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Period,
+    parser::{period_explicit, period_start},
+};
+
+/// period     = period-explicit / period-start
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9>
+pub fn period(input: &str) -> IResult<&str, Period> {
+    nom::branch::alt((period_explicit, period_start)).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, DateTime, Duration, Time, TimeForm};
+
+    use super::*;
+
+    #[test]
+    fn test_period() {
+        assert_eq!(
+            period("19970101T180000Z/19970102T070000Z"),
+            Ok((
+                "",
+                Period::Explicit(
+                    DateTime(Date(1997, 1, 1), Time(18, 0, 0, TimeForm::Utc)),
+                    DateTime(Date(1997, 1, 2), Time(7, 0, 0, TimeForm::Utc))
+                )
+            ))
+        );
+        assert_eq!(
+            period("19970101T180000Z/PT5H30M"),
+            Ok((
+                "",
+                Period::Start(
+                    DateTime(Date(1997, 1, 1), Time(18, 0, 0, TimeForm::Utc)),
+                    Duration(0, 19800)
+                )
+            ))
+        );
+        assert!(period("19970101T180000Z/").is_err());
+        assert!(period("").is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use nom::{IResult, Parser};
+
+use crate::{model::Period, parser::date_time};
+
+/// period-explicit = date-time "/" date-time
+/// ; [ISO.8601.2004] complete representation basic format for a
+/// ; period of time consisting of a start and end.  The start MUST
+/// ; be before the end.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9>
+pub fn period_explicit(input: &str) -> IResult<&str, Period> {
+    (date_time, nom::character::complete::char('/'), date_time)
+        .map(|(start, _, end)| Period::Explicit(start, end))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, DateTime, Time, TimeForm};
+
+    use super::*;
+
+    #[test]
+    fn test_period_explicit() {
+        assert_eq!(
+            period_explicit("19970101T180000Z/19970102T070000Z"),
+            Ok((
+                "",
+                Period::Explicit(
+                    DateTime(Date(1997, 1, 1), Time(18, 0, 0, TimeForm::Utc)),
+                    DateTime(Date(1997, 1, 2), Time(7, 0, 0, TimeForm::Utc))
+                )
+            ))
+        );
+        assert!(period_explicit("19970101T180000Z/PT5H30M").is_err());
+        assert!(period_explicit("19970101T180000Z").is_err());
+    }
+}
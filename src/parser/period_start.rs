@@ -0,0 +1,41 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Period,
+    parser::{date_time, dur_value},
+};
+
+/// period-start = date-time "/" dur-value
+/// ; [ISO.8601.2004] complete representation basic format for a
+/// ; period of time consisting of a start and positive duration
+/// ; of time.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9>
+pub fn period_start(input: &str) -> IResult<&str, Period> {
+    (date_time, nom::character::complete::char('/'), dur_value)
+        .map(|(start, _, duration)| Period::Start(start, duration))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Date, DateTime, Duration, Time, TimeForm};
+
+    use super::*;
+
+    #[test]
+    fn test_period_start() {
+        assert_eq!(
+            period_start("19970101T180000Z/PT5H30M"),
+            Ok((
+                "",
+                Period::Start(
+                    DateTime(Date(1997, 1, 1), Time(18, 0, 0, TimeForm::Utc)),
+                    Duration(0, 19800)
+                )
+            ))
+        );
+        assert!(period_start("19970101T180000Z/19970102T070000Z").is_err());
+        assert!(period_start("19970101T180000Z").is_err());
+    }
+}
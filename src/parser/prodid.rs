@@ -1,34 +1,33 @@
 use nom::{IResult, Parser};
 
 use crate::{
-    model::{Property, PropertyName},
-    parser::pidvalue,
+    model::Property,
+    parser::{other_param, pidvalue},
 };
 
 /// prodid     = "PRODID" pidparam ":" pidvalue CRLF
 ///
+/// pidparam   = *(";" other-param)
+///
 /// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.3>
 pub fn prodid(input: &str) -> IResult<&str, Property> {
     (
-        nom::bytes::complete::tag("PRODID"),
-        // FIXME:
+        nom::bytes::complete::tag_no_case("PRODID"),
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            other_param,
+        )),
         nom::character::complete::char(':'),
         pidvalue,
         nom::character::complete::line_ending,
     )
-        .map(|(_, _, pidvalue, _)| {
-            Property(
-                PropertyName("PRODID".to_owned()),
-                Default::default(),
-                pidvalue,
-            )
-        })
+        .map(|(name, pidparam, _, pidvalue, _)| Property::new(name.to_owned(), pidparam, pidvalue))
         .parse(input)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::model::{PropertyValue, Text};
+    use crate::model::{ParameterName, ParameterValue, PropertyName, PropertyValue, Text};
 
     use super::*;
 
@@ -40,7 +39,7 @@ mod tests {
             Ok((
                 "",
                 Property(
-                    PropertyName("PRODID".to_owned()),
+                    PropertyName::new("PRODID"),
                     Default::default(),
                     PropertyValue::Text(Text("-//Example Corp//NONSGML Example//EN".to_owned())),
                 )
@@ -48,6 +47,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_with_pidparam() {
+        let input = "PRODID;X-ABC-PARAM=a:-//Example Corp//NONSGML Example//EN\r\n";
+        assert_eq!(
+            prodid(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("PRODID"),
+                    [(
+                        ParameterName::new("X-ABC-PARAM"),
+                        ParameterValue(vec!["a".to_owned()])
+                    )]
+                    .into_iter()
+                    .collect(),
+                    PropertyValue::Text(Text("-//Example Corp//NONSGML Example//EN".to_owned())),
+                )
+            ))
+        );
+    }
+
     #[test]
     fn test_invalid() {
         let input = "INVALID:-//Example Corp//NONSGML Example//EN\r\n";
@@ -0,0 +1,477 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::{Boolean, Geo, Property, PropertyValue, Text, ValueType},
+    parser::{
+        binary, boolean, cal_address, contentline, date_time, date_value, dur_value, float,
+        geovalue, integer, period, statcode, text, time, uri, utc_offset, value,
+    },
+};
+
+/// A content line whose value is typed by the VALUE parameter, or by the
+/// default value type of the property if there is none.
+///
+/// The VALUE parameter must be one allowed for the property if RFC 5545
+/// defines it, and the TZID parameter is applied to a DATE-TIME or PERIOD
+/// value.
+/// Values of a type not defined by RFC 5545 (an x-name or an iana-token),
+/// and of an x-prop or an iana-prop without VALUE, are kept as
+/// [`PropertyValue::XType`].
+///
+/// The values of CATEGORIES, RESOURCES, RDATE, EXDATE and FREEBUSY are
+/// split at the commas into a [`PropertyValue::List`], and the value of
+/// REQUEST-STATUS at the semicolons into a [`PropertyValue::Structured`];
+/// an escaped comma or semicolon in TEXT does not split the value.
+pub fn property(input: &str) -> IResult<&str, Property> {
+    let (rest, (name, params, value)) = contentline(input)?;
+    let error = || nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify));
+    // names are case-insensitive, so the checks use the uppercase name
+    let canonical = name.to_ascii_uppercase();
+    let parameter = |param_name: &str| {
+        params
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(param_name))
+            .map(|(_, values)| values.as_slice())
+    };
+
+    let value_type = match parameter("VALUE") {
+        None => ValueType::default_for(&canonical),
+        Some([value_type]) => match ValueType::from(value_type.as_str()) {
+            ValueType::XName(_) | ValueType::IanaToken(_) => None,
+            value_type if value_type.is_allowed_for(&canonical) => Some(value_type),
+            _ => return Err(error()),
+        },
+        Some(_) => return Err(error()),
+    };
+    let value = match value_type {
+        None => PropertyValue::XType(value),
+        // GEO is a FLOAT for latitude and another for longitude
+        Some(ValueType::Float) if canonical == "GEO" => {
+            let (_, (latitude, longitude)) = nom::combinator::all_consuming(geovalue)
+                .parse(value.as_str())
+                .map_err(|_| error())?;
+            PropertyValue::Geo(Geo::new(latitude, longitude).ok_or_else(error)?)
+        }
+        Some(ValueType::Text) if canonical == "REQUEST-STATUS" => {
+            let (_, value) = nom::combinator::all_consuming(rstatus_value)
+                .parse(value.as_str())
+                .map_err(|_| error())?;
+            value
+        }
+        Some(value_type)
+            if matches!(
+                canonical.as_str(),
+                "CATEGORIES" | "RESOURCES" | "RDATE" | "EXDATE" | "FREEBUSY"
+            ) =>
+        {
+            let (_, values) = nom::combinator::all_consuming(nom::multi::separated_list1(
+                nom::character::complete::char(','),
+                property_value(value_type),
+            ))
+            .parse(value.as_str())
+            .map_err(|_| error())?;
+            PropertyValue::List(values)
+        }
+        Some(value_type) => {
+            let (_, value) = nom::combinator::all_consuming(property_value(value_type))
+                .parse(value.as_str())
+                .map_err(|_| error())?;
+            value
+        }
+    };
+    let value = match parameter("TZID") {
+        Some([tzid]) => with_tzid(value, tzid).ok_or_else(error)?,
+        Some(_) => return Err(error()),
+        None => value,
+    };
+    Ok((rest, Property::new(name, params, value)))
+}
+
+/// The parser for the values of the value type.
+pub fn property_value<'a>(
+    value_type: ValueType,
+) -> impl Parser<&'a str, Output = PropertyValue, Error = nom::error::Error<&'a str>> {
+    move |input: &'a str| match &value_type {
+        ValueType::Binary => binary.map(PropertyValue::Binary).parse(input),
+        ValueType::Boolean => boolean
+            .map(|boolean| PropertyValue::Boolean(boolean == Boolean::True))
+            .parse(input),
+        ValueType::CalAddress => cal_address.map(PropertyValue::CalAddress).parse(input),
+        ValueType::Date => date_value.map(PropertyValue::Date).parse(input),
+        ValueType::DateTime => date_time.map(PropertyValue::DateTime).parse(input),
+        ValueType::Duration => dur_value.map(PropertyValue::Duration).parse(input),
+        ValueType::Float => float.map(PropertyValue::Float).parse(input),
+        ValueType::Integer => integer.map(PropertyValue::Integer).parse(input),
+        ValueType::Period => period.map(PropertyValue::Period).parse(input),
+        ValueType::Recur => value.map(PropertyValue::Recur).parse(input),
+        ValueType::Text => text.map(PropertyValue::Text).parse(input),
+        ValueType::Time => time.map(PropertyValue::Time).parse(input),
+        ValueType::Uri => uri.map(PropertyValue::Uri).parse(input),
+        ValueType::UtcOffset => utc_offset.map(PropertyValue::UtcOffset).parse(input),
+        ValueType::XName(_) | ValueType::IanaToken(_) => {
+            value.map(PropertyValue::XType).parse(input)
+        }
+    }
+}
+
+/// statcode ";" statdesc [";" extdata]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.8.3>
+fn rstatus_value(input: &str) -> IResult<&str, PropertyValue> {
+    (
+        statcode,
+        nom::sequence::preceded(nom::character::complete::char(';'), text),
+        nom::combinator::opt(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            text,
+        )),
+    )
+        .map(|(statcode, statdesc, extdata)| {
+            let mut values = vec![
+                PropertyValue::Text(Text(statcode)),
+                PropertyValue::Text(statdesc),
+            ];
+            values.extend(extdata.map(PropertyValue::Text));
+            PropertyValue::Structured(values)
+        })
+        .parse(input)
+}
+
+/// Refers the DATE-TIME and PERIOD values to the time zone `tzid`, leaving
+/// the values of other types as they are.
+///
+/// Returns `None` if any of them is a UTC time.
+fn with_tzid(value: PropertyValue, tzid: &str) -> Option<PropertyValue> {
+    match value {
+        PropertyValue::DateTime(date_time) => {
+            date_time.with_tzid(tzid).map(PropertyValue::DateTime)
+        }
+        PropertyValue::Period(period) => period.with_tzid(tzid).map(PropertyValue::Period),
+        PropertyValue::List(values) => values
+            .into_iter()
+            .map(|value| with_tzid(value, tzid))
+            .collect::<Option<Vec<PropertyValue>>>()
+            .map(PropertyValue::List),
+        value => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+    use crate::model::{
+        Date, DateTime, Duration, Parameter, ParameterName, ParameterValue, Period, PropertyName,
+        Time, TimeForm, Uri,
+    };
+
+    use super::*;
+
+    fn value(input: &str) -> Option<PropertyValue> {
+        property(input)
+            .ok()
+            .map(|(_, property)| property.value().clone())
+    }
+
+    #[test]
+    fn test_default_value_types() {
+        assert_eq!(
+            value("DTSTART:19970714T133000\r\n"),
+            Some(PropertyValue::DateTime(DateTime(
+                Date(1997, 7, 14),
+                Time(13, 30, 0, TimeForm::Floating)
+            )))
+        );
+        assert_eq!(
+            value("SUMMARY:Bastille Day Party\\, 1997\r\n"),
+            Some(PropertyValue::Text(Text(
+                "Bastille Day Party, 1997".to_owned()
+            )))
+        );
+        assert_eq!(
+            value("DURATION:PT1H\r\n"),
+            Some(PropertyValue::Duration(Duration(0, 3600)))
+        );
+        assert_eq!(value("PRIORITY:1\r\n"), Some(PropertyValue::Integer(1)));
+        assert_eq!(
+            value("GEO:37.386013;-122.082932\r\n"),
+            Some(PropertyValue::Geo(Geo(37.386013, -122.082932)))
+        );
+        assert_eq!(
+            value("URL:http://example.com/pub/calendars/jsmith/mytime.ics\r\n"),
+            Uri::new("http://example.com/pub/calendars/jsmith/mytime.ics").map(PropertyValue::Uri)
+        );
+        assert_eq!(
+            value("RRULE:FREQ=YEARLY;BYMONTH=7;BYMONTHDAY=14\r\n"),
+            Some(PropertyValue::Recur(
+                "FREQ=YEARLY;BYMONTH=7;BYMONTHDAY=14".to_owned()
+            ))
+        );
+        assert_eq!(value("DTSTART:19970714\r\n"), None);
+        assert_eq!(value("PRIORITY:high\r\n"), None);
+    }
+
+    #[test]
+    fn test_value_parameter() {
+        assert_eq!(
+            property("DTSTART;VALUE=DATE:19970714\r\n"),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("DTSTART"),
+                    [(
+                        ParameterName::new("VALUE"),
+                        ParameterValue(vec!["DATE".to_owned()]),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    PropertyValue::Date(Date(1997, 7, 14)),
+                )
+            ))
+        );
+        assert_eq!(
+            value("RDATE;VALUE=PERIOD:19960403T020000Z/PT2H\r\n"),
+            Some(PropertyValue::List(vec![PropertyValue::Period(
+                Period::Start(
+                    DateTime(Date(1996, 4, 3), Time(2, 0, 0, TimeForm::Utc)),
+                    Duration(0, 2 * 3600)
+                )
+            )]))
+        );
+        assert_eq!(
+            value("X-ABC;VALUE=INTEGER:42\r\n"),
+            Some(PropertyValue::Integer(42))
+        );
+        assert_eq!(
+            value("X-ABC;VALUE=X-NUMBER:42\r\n"),
+            Some(PropertyValue::XType("42".to_owned()))
+        );
+        assert_eq!(
+            value("X-ABC:42\r\n"),
+            Some(PropertyValue::XType("42".to_owned()))
+        );
+        assert_eq!(
+            value("DTSTART;VALUE=X-FOO:19970714T133000\r\n"),
+            Some(PropertyValue::XType("19970714T133000".to_owned()))
+        );
+        assert_eq!(
+            value("CATEGORIES;VALUE=FOO:a,b\r\n"),
+            Some(PropertyValue::XType("a,b".to_owned()))
+        );
+        assert_eq!(
+            value("DTSTART;VALUE=PERIOD:19960403T020000Z/PT2H\r\n"),
+            None
+        );
+        assert_eq!(value("DTSTART;VALUE=DATE,DATE-TIME:19970714\r\n"), None);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let input = "dtstart;value=date:19970714\r\n";
+        let Ok((_, dtstart)) = property(input) else {
+            panic!("DTSTART is invalid");
+        };
+        assert_eq!(dtstart.name(), &PropertyName::new("DTSTART"));
+        assert_eq!(dtstart.name().original(), "dtstart");
+        assert_eq!(dtstart.value(), &PropertyValue::Date(Date(1997, 7, 14)));
+        assert_eq!(
+            dtstart.parameter("VALUE"),
+            Some(&ParameterValue(vec!["date".to_owned()]))
+        );
+        assert_eq!(dtstart.to_string(), input);
+
+        assert_eq!(
+            value("X-abc;Value=Boolean:true\r\n"),
+            Some(PropertyValue::Boolean(true))
+        );
+    }
+
+    #[test]
+    fn test_tzid_parameter() {
+        assert_eq!(
+            value("DTSTART;TZID=America/New_York:19970714T133000\r\n"),
+            Some(PropertyValue::DateTime(DateTime(
+                Date(1997, 7, 14),
+                Time(13, 30, 0, TimeForm::Zoned("America/New_York".to_owned()))
+            )))
+        );
+        assert_eq!(
+            value("DTSTART;TZID=America/New_York:19970714T133000Z\r\n"),
+            None
+        );
+        let zoned = |hour| {
+            DateTime(
+                Date(1996, 4, 3),
+                Time(hour, 0, 0, TimeForm::Zoned("America/New_York".to_owned())),
+            )
+        };
+        assert_eq!(
+            value(
+                "RDATE;VALUE=PERIOD;TZID=America/New_York:19960403T020000/19960403T040000,19960403T060000/PT2H\r\n"
+            ),
+            Some(PropertyValue::List(vec![
+                PropertyValue::Period(Period::Explicit(zoned(2), zoned(4))),
+                PropertyValue::Period(Period::Start(zoned(6), Duration(0, 2 * 3600))),
+            ]))
+        );
+        assert_eq!(
+            value("RDATE;VALUE=PERIOD;TZID=America/New_York:19960403T020000/19960403T040000Z\r\n"),
+            None
+        );
+        assert_eq!(
+            value("DTSTART;TZID=America/New_York;VALUE=DATE:19970714\r\n"),
+            Some(PropertyValue::Date(Date(1997, 7, 14)))
+        );
+    }
+
+    #[test]
+    fn test_list_values() {
+        let text = |s: &str| PropertyValue::Text(Text(s.to_owned()));
+        assert_eq!(
+            value("CATEGORIES:APPOINTMENT,EDUCATION\r\n"),
+            Some(PropertyValue::List(vec![
+                text("APPOINTMENT"),
+                text("EDUCATION")
+            ]))
+        );
+        assert_eq!(
+            value("CATEGORIES:Meetings\\, Calls,Work\r\n"),
+            Some(PropertyValue::List(vec![
+                text("Meetings, Calls"),
+                text("Work")
+            ]))
+        );
+        assert_eq!(
+            value("RESOURCES:EASEL\r\n"),
+            Some(PropertyValue::List(vec![text("EASEL")]))
+        );
+        assert_eq!(
+            value("EXDATE;TZID=America/New_York:19960402T010000,19960403T010000\r\n"),
+            Some(PropertyValue::List(vec![
+                PropertyValue::DateTime(DateTime(
+                    Date(1996, 4, 2),
+                    Time(1, 0, 0, TimeForm::Zoned("America/New_York".to_owned()))
+                )),
+                PropertyValue::DateTime(DateTime(
+                    Date(1996, 4, 3),
+                    Time(1, 0, 0, TimeForm::Zoned("America/New_York".to_owned()))
+                )),
+            ]))
+        );
+        assert_eq!(
+            value("RDATE;VALUE=DATE:19970101,19970120\r\n"),
+            Some(PropertyValue::List(vec![
+                PropertyValue::Date(Date(1997, 1, 1)),
+                PropertyValue::Date(Date(1997, 1, 20)),
+            ]))
+        );
+        assert_eq!(value("EXDATE:19960402T010000Z,\r\n"), None);
+        assert_eq!(value("SUMMARY:a,b\r\n"), None);
+    }
+
+    #[test]
+    fn test_structured_values() {
+        let text = |s: &str| PropertyValue::Text(Text(s.to_owned()));
+        assert_eq!(
+            value("REQUEST-STATUS:2.0;Success\r\n"),
+            Some(PropertyValue::Structured(vec![
+                text("2.0"),
+                text("Success")
+            ]))
+        );
+        assert_eq!(
+            value("REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01\r\n"),
+            Some(PropertyValue::Structured(vec![
+                text("3.1"),
+                text("Invalid property value"),
+                text("DTSTART:96-Apr-01")
+            ]))
+        );
+        assert_eq!(
+            value("REQUEST-STATUS:2.8; Success\\, repeating event ignored;RRULE\r\n"),
+            Some(PropertyValue::Structured(vec![
+                text("2.8"),
+                text(" Success, repeating event ignored"),
+                text("RRULE")
+            ]))
+        );
+        assert_eq!(value("REQUEST-STATUS:2.0\r\n"), None);
+        assert_eq!(value("REQUEST-STATUS:2.0;a;b;c\r\n"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        for input in [
+            "CATEGORIES:Meetings\\, Calls,Work\r\n",
+            "REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01\r\n",
+            "RDATE;VALUE=DATE:19970101,19970120\r\n",
+            "DESCRIPTION:a\\nb\\;c\\\\d\r\n",
+        ] {
+            assert_eq!(
+                property(input).map(|(_, property)| property.to_string()),
+                Ok(input.to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn test_parameter_order_and_duplicates() {
+        let input = "ATTENDEE;ROLE=CHAIR;CN=\"Doe, John\";X-A=1;X-A=2:mailto:jdoe@example.com\r\n";
+        let Ok((_, attendee)) = property(input) else {
+            panic!("ATTENDEE is invalid");
+        };
+        assert_eq!(attendee.to_string(), input);
+        assert_eq!(
+            attendee
+                .parameters()
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["ROLE", "CN", "X-A", "X-A"]
+        );
+        assert_eq!(
+            attendee.parameters().duplicates(),
+            vec![&ParameterName::new("X-A")]
+        );
+        assert_eq!(
+            attendee.parameters().get("X-A"),
+            Some(&ParameterValue(vec!["1".to_owned()]))
+        );
+        assert_eq!(attendee.typed_parameters().map(|p| p.len()), Ok(4));
+
+        let Ok((_, summary)) = property("SUMMARY;LANGUAGE=en;LANGUAGE=fr:Hello\r\n") else {
+            panic!("SUMMARY is invalid");
+        };
+        assert_eq!(
+            summary.typed_parameters(),
+            Err(ValidationError::DuplicateParameter(
+                PropertyName::new("SUMMARY"),
+                ParameterName::new("LANGUAGE")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_caret_encoding() {
+        let input = "ATTENDEE;CN=\"^'Doe^', John^nSmith\";X-A=^^:mailto:jdoe@example.com\r\n";
+        let Ok((_, attendee)) = property(input) else {
+            panic!("ATTENDEE is invalid");
+        };
+        assert_eq!(
+            attendee.parameter("CN"),
+            Some(&ParameterValue(vec!["\"Doe\", John\nSmith".to_owned()]))
+        );
+        assert_eq!(
+            attendee.parameter("X-A"),
+            Some(&ParameterValue(vec!["^".to_owned()]))
+        );
+        assert_eq!(attendee.to_string(), input);
+
+        let Ok((_, attendee)) = property("ATTENDEE;CN=a^nb:mailto:a@example.com\r\n") else {
+            panic!("ATTENDEE is invalid");
+        };
+        assert_eq!(
+            attendee.typed_parameters(),
+            Ok(vec![Parameter::Cn("a\nb".to_owned())])
+        );
+    }
+}
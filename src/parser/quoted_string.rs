@@ -1,14 +1,17 @@
 use nom::{IResult, Parser};
 
-use crate::parser::qsafe_char;
+use crate::parser::{caret_escape, qsafe_char};
 
 /// quoted-string = DQUOTE *QSAFE-CHAR DQUOTE
 ///
+/// The caret encoding is decoded, see [`caret_escape`].
+///
 /// <https://datatracker.ietf.org/doc/html/rfc5545>
+/// <https://datatracker.ietf.org/doc/html/rfc6868>
 pub fn quoted_string(input: &str) -> IResult<&str, String> {
     nom::sequence::delimited(
         nom::character::complete::char('"'),
-        nom::multi::many0(qsafe_char),
+        nom::multi::many0(nom::branch::alt((caret_escape, qsafe_char))),
         nom::character::complete::char('"'),
     )
     .map(|chars| chars.iter().collect::<String>())
@@ -30,5 +33,12 @@ mod tests {
             quoted_string("\"he\"llo\""),
             Ok(("llo\"", "he".to_string()))
         );
+
+        // caret encoding
+        assert_eq!(
+            quoted_string("\"^'Doe^', John^nSmith\""),
+            Ok(("", "\"Doe\", John\nSmith".to_string()))
+        );
+        assert_eq!(quoted_string("\"^^^x\""), Ok(("", "^^x".to_string())));
     }
 }
@@ -0,0 +1,35 @@
+use nom::{IResult, Parser};
+
+/// statcode   = 1*DIGIT 1*2("." 1*DIGIT)
+/// ;Hierarchical, numeric return status code
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.8.3>
+pub fn statcode(input: &str) -> IResult<&str, String> {
+    nom::combinator::recognize((
+        nom::character::complete::digit1,
+        nom::multi::many_m_n(
+            1,
+            2,
+            (
+                nom::character::complete::char('.'),
+                nom::character::complete::digit1,
+            ),
+        ),
+    ))
+    .map(str::to_owned)
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statcode() {
+        assert_eq!(statcode("2.0"), Ok(("", "2.0".to_owned())));
+        assert_eq!(statcode("3.1.3"), Ok(("", "3.1.3".to_owned())));
+        assert_eq!(statcode("3.1.3.4"), Ok((".4", "3.1.3".to_owned())));
+        assert!(statcode("2").is_err());
+        assert!(statcode(".0").is_err());
+    }
+}
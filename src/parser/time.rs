@@ -0,0 +1,50 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::{Time, TimeForm},
+    parser::{time_hour, time_minute, time_second},
+};
+
+/// time         = time-hour time-minute time-second [time-utc]
+///
+/// time-utc     = "Z"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.12>
+pub fn time(input: &str) -> IResult<&str, Time> {
+    (
+        time_hour,
+        time_minute,
+        time_second,
+        nom::combinator::opt(nom::character::complete::char('Z')),
+    )
+        .map(|(hour, minute, second, utc)| {
+            let form = match utc {
+                Some(_) => TimeForm::Utc,
+                None => TimeForm::Floating,
+            };
+            Time(hour, minute, second, form)
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time() {
+        assert_eq!(time("230000"), Ok(("", Time(23, 0, 0, TimeForm::Floating))));
+        assert_eq!(time("070000Z"), Ok(("", Time(7, 0, 0, TimeForm::Utc))));
+        assert_eq!(
+            time("083000/"),
+            Ok(("/", Time(8, 30, 0, TimeForm::Floating)))
+        );
+        assert_eq!(time("235960Z"), Ok(("", Time(23, 59, 60, TimeForm::Utc))));
+        assert!(time("").is_err());
+        assert!(time("2300").is_err());
+        assert!(time("23000A").is_err());
+        assert!(time("240000").is_err());
+        assert!(time("236000").is_err());
+        assert!(time("235961").is_err());
+    }
+}
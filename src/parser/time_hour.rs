@@ -0,0 +1,31 @@
+use nom::{IResult, Parser};
+
+/// time-hour    = 2DIGIT        ;00-23
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.12>
+pub fn time_hour(input: &str) -> IResult<&str, u8> {
+    nom::combinator::verify(
+        nom::combinator::map_res(
+            nom::bytes::complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+            str::parse,
+        ),
+        |hour: &u8| *hour <= 23,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_hour() {
+        assert_eq!(time_hour("00"), Ok(("", 0)));
+        assert_eq!(time_hour("23"), Ok(("", 23)));
+        assert_eq!(time_hour("123"), Ok(("3", 12)));
+        assert!(time_hour("").is_err());
+        assert!(time_hour("1").is_err());
+        assert!(time_hour("A1").is_err());
+        assert!(time_hour("24").is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use nom::{IResult, Parser};
+
+/// time-minute  = 2DIGIT        ;00-59
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.12>
+pub fn time_minute(input: &str) -> IResult<&str, u8> {
+    nom::combinator::verify(
+        nom::combinator::map_res(
+            nom::bytes::complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+            str::parse,
+        ),
+        |minute: &u8| *minute <= 59,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_minute() {
+        assert_eq!(time_minute("00"), Ok(("", 0)));
+        assert_eq!(time_minute("59"), Ok(("", 59)));
+        assert_eq!(time_minute("123"), Ok(("3", 12)));
+        assert!(time_minute("").is_err());
+        assert!(time_minute("1").is_err());
+        assert!(time_minute("A1").is_err());
+        assert!(time_minute("60").is_err());
+    }
+}
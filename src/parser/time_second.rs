@@ -0,0 +1,32 @@
+use nom::{IResult, Parser};
+
+/// time-second  = 2DIGIT        ;00-60
+/// ;The "60" value is used to account for positive "leap" seconds.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.12>
+pub fn time_second(input: &str) -> IResult<&str, u8> {
+    nom::combinator::verify(
+        nom::combinator::map_res(
+            nom::bytes::complete::take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+            str::parse,
+        ),
+        |second: &u8| *second <= 60,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_second() {
+        assert_eq!(time_second("00"), Ok(("", 0)));
+        assert_eq!(time_second("60"), Ok(("", 60)));
+        assert_eq!(time_second("123"), Ok(("3", 12)));
+        assert!(time_second("").is_err());
+        assert!(time_second("1").is_err());
+        assert!(time_second("A1").is_err());
+        assert!(time_second("61").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+/// A logical content line, i.e. one or more physical lines joined by
+/// removing each CRLF immediately followed by a single linear white-space
+/// character.
+///
+/// The line break that terminates the last physical line is kept, so the
+/// text can be passed to [`contentline`](crate::parser::contentline) as is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogicalLine {
+    pub(crate) text: String,
+    pub(crate) lines: Range<usize>,
+    pub(crate) folds: Vec<usize>,
+}
+
+impl LogicalLine {
+    /// Returns the unfolded text, including the terminating line break.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the range of the (1-based) physical line numbers.
+    pub fn lines(&self) -> Range<usize> {
+        self.lines.clone()
+    }
+
+    /// Maps a byte offset in the unfolded text to the (1-based) physical
+    /// line and column in the original input.
+    ///
+    /// An offset inside a multi-byte character is rounded down to the start
+    /// of the character.
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let index = self
+            .folds
+            .iter()
+            .rposition(|start| *start <= offset)
+            .unwrap_or_default();
+        let start = self.folds[index];
+        // continuation lines start with the removed white-space character
+        let indent = if index == 0 { 0 } else { 1 };
+        let column = indent + self.text[start..offset].chars().count() + 1;
+        (self.lines.start + index, column)
+    }
+}
+
+/// An iterator over the logical lines of an iCalendar stream.
+///
+/// Created by [`unfold`].
+#[derive(Clone, Debug)]
+pub struct Unfold<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl Iterator for Unfold<'_> {
+    type Item = LogicalLine;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let start = self.line + 1;
+        let (content, mut ending, rest) = split_physical_line(self.rest);
+        let mut text = content.to_owned();
+        let mut folds = vec![0];
+        self.rest = rest;
+        self.line += 1;
+
+        while !ending.is_empty() && self.rest.starts_with([' ', '\t']) {
+            let (content, next_ending, rest) = split_physical_line(&self.rest[1..]);
+            folds.push(text.len());
+            text.push_str(content);
+            ending = next_ending;
+            self.rest = rest;
+            self.line += 1;
+        }
+        text.push_str(ending);
+
+        Some(LogicalLine {
+            text,
+            lines: start..self.line + 1,
+            folds,
+        })
+    }
+}
+
+/// Unfolding
+///
+/// > Unfolding is accomplished by removing the CRLF and the linear
+/// > white-space character that immediately follows.
+/// >
+/// > When parsing a content line, folded lines MUST first be unfolded
+/// > according to the unfolding procedure described above.
+///
+/// A bare LF is accepted as a line break as well, like
+/// [`contentline`](crate::parser::contentline) does.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.1>
+pub fn unfold(input: &str) -> Unfold<'_> {
+    Unfold {
+        rest: input,
+        line: 0,
+    }
+}
+
+/// Splits off the first physical line as `(content, line break, rest)`.
+fn split_physical_line(input: &str) -> (&str, &str, &str) {
+    match input.find('\n') {
+        Some(index) => {
+            let (line, rest) = input.split_at(index + 1);
+            let content_len = line.strip_suffix("\r\n").unwrap_or(&line[..index]).len();
+            let (content, ending) = line.split_at(content_len);
+            (content, ending, rest)
+        }
+        None => (input, "", ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfold_without_folding() {
+        let input = "BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n";
+        assert_eq!(
+            unfold(input).collect::<Vec<LogicalLine>>(),
+            vec![
+                LogicalLine {
+                    text: "BEGIN:VCALENDAR\r\n".to_owned(),
+                    lines: 1..2,
+                    folds: vec![0],
+                },
+                LogicalLine {
+                    text: "END:VCALENDAR\r\n".to_owned(),
+                    lines: 2..3,
+                    folds: vec![0],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unfold_with_folding() {
+        let input = "DESCRIPTION:This is a lo\r\n ng description\r\n\t that exists on a long line.\r\nUID:1\r\n";
+        let lines = unfold(input).collect::<Vec<LogicalLine>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].as_str(),
+            "DESCRIPTION:This is a long description that exists on a long line.\r\n"
+        );
+        assert_eq!(lines[0].lines(), 1..4);
+        assert_eq!(lines[1].as_str(), "UID:1\r\n");
+        assert_eq!(lines[1].lines(), 4..5);
+    }
+
+    #[test]
+    fn test_unfold_keeps_white_space_after_the_first_one() {
+        let input = "SUMMARY:a\r\n  b\r\n";
+        let lines = unfold(input).collect::<Vec<LogicalLine>>();
+        assert_eq!(lines[0].as_str(), "SUMMARY:a b\r\n");
+    }
+
+    #[test]
+    fn test_unfold_with_lf() {
+        let input = "SUMMARY:a\n b\nUID:1";
+        let lines = unfold(input).collect::<Vec<LogicalLine>>();
+        assert_eq!(lines[0].as_str(), "SUMMARY:ab\n");
+        assert_eq!(lines[1].as_str(), "UID:1");
+        assert_eq!(lines[1].lines(), 3..4);
+    }
+
+    #[test]
+    fn test_unfold_does_not_fold_into_the_first_line() {
+        let input = " SUMMARY:a\r\n";
+        let lines = unfold(input).collect::<Vec<LogicalLine>>();
+        assert_eq!(lines[0].as_str(), " SUMMARY:a\r\n");
+    }
+
+    #[test]
+    fn test_unfold_empty() {
+        assert_eq!(unfold("").next(), None);
+    }
+
+    #[test]
+    fn test_position() {
+        let input = "SUMMARY:ab\r\n cd\r\n éf\r\n";
+        let Some(line) = unfold(input).next() else {
+            panic!("no logical line");
+        };
+        assert_eq!(line.as_str(), "SUMMARY:abcdéf\r\n");
+        assert_eq!(line.position(0), (1, 1));
+        assert_eq!(line.position(9), (1, 10)); // b
+        assert_eq!(line.position(10), (2, 2)); // c
+        assert_eq!(line.position(11), (2, 3)); // d
+        assert_eq!(line.position(12), (3, 2)); // é
+        assert_eq!(line.position(13), (3, 2)); // inside é
+        assert_eq!(line.position(14), (3, 3)); // f
+    }
+}
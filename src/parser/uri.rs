@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+use nom::{IResult, Parser};
+use nom_locate::LocatedSpan;
+
+use crate::model::{Uri, UriComponents};
+
+type Span<'a> = LocatedSpan<&'a str>;
+
+/// uri = <As defined in Section 3 of [RFC3986]>
+///
+/// The components of the URI are located when it is parsed, and kept with
+/// it. nom-uri validates the URI; the components are then split off the
+/// text it accepted, in a single linear pass.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.13>
+pub fn uri(input: &str) -> IResult<&str, Uri> {
+    let (i, t) = nom_uri::uri(LocatedSpan::new(input)).map_err(|e| e.map_input(|_| input))?;
+    let (_, components) =
+        components(LocatedSpan::new(t.span.fragment())).map_err(|e| e.map_input(|_| input))?;
+    Ok((i.fragment(), Uri(t.span.fragment().to_string(), components)))
+}
+
+/// Locates the components of a URI, as the regular expression of
+/// <https://datatracker.ietf.org/doc/html/rfc3986#appendix-B> does.
+///
+/// ^(([^:/?#]+):)?(//([^/?#]*))?([^?#]*)(\?([^#]*))?(#(.*))?
+///
+/// RFC 3986 gives this split for any well-formed URI, and the input has
+/// already been accepted by nom-uri, so the two agree: the first ":" ends
+/// the scheme since a scheme has no ":", an authority (e.g. an IPv6 literal
+/// with ":" in it) ends at the first "/", "?" or "#" since none may occur
+/// in it, and a "?" in the fragment does not start a query.
+fn components(input: Span<'_>) -> IResult<Span<'_>, UriComponents> {
+    let till = |chars: &'static str| nom::bytes::complete::take_till(move |c| chars.contains(c));
+    (
+        nom::sequence::terminated(
+            nom::bytes::complete::take_till1(|c| ":/?#".contains(c)),
+            nom::character::complete::char(':'),
+        ),
+        nom::combinator::opt(nom::sequence::preceded(
+            nom::bytes::complete::tag("//"),
+            till("/?#"),
+        )),
+        till("?#"),
+        nom::combinator::opt(nom::sequence::preceded(
+            nom::character::complete::char('?'),
+            till("#"),
+        )),
+        nom::combinator::opt(nom::sequence::preceded(
+            nom::character::complete::char('#'),
+            nom::combinator::rest,
+        )),
+    )
+        .map(|(scheme, authority, path, query, fragment)| UriComponents {
+            scheme: range(scheme),
+            authority: authority.map(range),
+            path: range(path),
+            query: query.map(range),
+            fragment: fragment.map(range),
+        })
+        .parse(input)
+}
+
+fn range(span: Span<'_>) -> Range<usize> {
+    span.location_offset()..span.location_offset() + span.fragment().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri() {
+        assert!(uri("").is_err());
+        assert!(uri("example.com").is_err());
+        assert_eq!(
+            uri("http://example.com/pub/calendars/jsmith/mytime.ics"),
+            Ok((
+                "",
+                Uri(
+                    "http://example.com/pub/calendars/jsmith/mytime.ics".to_owned(),
+                    UriComponents {
+                        scheme: 0..4,
+                        authority: Some(7..18),
+                        path: 18..50,
+                        query: None,
+                        fragment: None,
+                    }
+                )
+            ))
+        );
+        assert_eq!(
+            uri("mailto:jane_doe@example.com"),
+            Ok((
+                "",
+                Uri(
+                    "mailto:jane_doe@example.com".to_owned(),
+                    UriComponents {
+                        scheme: 0..6,
+                        authority: None,
+                        path: 7..27,
+                        query: None,
+                        fragment: None,
+                    }
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_uri_components() {
+        for (input, expected) in [
+            (
+                "http://[2001:db8::7]:8080/a:b?c=d#e",
+                (
+                    "http",
+                    Some("[2001:db8::7]:8080"),
+                    "/a:b",
+                    Some("c=d"),
+                    Some("e"),
+                ),
+            ),
+            (
+                "file:///etc/hosts",
+                ("file", Some(""), "/etc/hosts", None, None),
+            ),
+            (
+                "http://example.com#a?b",
+                ("http", Some("example.com"), "", None, Some("a?b")),
+            ),
+            (
+                "http://example.com/a?b/c?d#",
+                ("http", Some("example.com"), "/a", Some("b/c?d"), Some("")),
+            ),
+        ] {
+            let Some(uri) = Uri::new(input) else {
+                panic!("not a URI: {input}");
+            };
+            assert_eq!(
+                (
+                    uri.scheme(),
+                    uri.authority(),
+                    uri.path(),
+                    uri.query(),
+                    uri.fragment()
+                ),
+                expected
+            );
+        }
+    }
+}
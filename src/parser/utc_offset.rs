@@ -0,0 +1,56 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::UtcOffset,
+    parser::{time_hour, time_minute, time_second},
+};
+
+/// utc-offset = time-numzone
+///
+/// time-numzone = ("+" / "-") time-hour time-minute [time-second]
+///
+/// The value "-0000" is not allowed.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.14>
+pub fn utc_offset(input: &str) -> IResult<&str, UtcOffset> {
+    nom::combinator::verify(
+        (
+            nom::character::complete::one_of("+-"),
+            time_hour,
+            time_minute,
+            nom::combinator::opt(time_second),
+        ),
+        |(sign, hour, minute, second)| {
+            *sign == '+' || *hour != 0 || *minute != 0 || second.is_some_and(|second| second != 0)
+        },
+    )
+    .map(|(sign, hour, minute, second)| {
+        let seconds =
+            i32::from(hour) * 3600 + i32::from(minute) * 60 + i32::from(second.unwrap_or_default());
+        UtcOffset(if sign == '-' { -seconds } else { seconds })
+    })
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utc_offset() {
+        assert_eq!(utc_offset("-0500"), Ok(("", UtcOffset(-5 * 3600))));
+        assert_eq!(utc_offset("+0100"), Ok(("", UtcOffset(3600))));
+        assert_eq!(utc_offset("+0000"), Ok(("", UtcOffset(0))));
+        assert_eq!(utc_offset("+0530"), Ok(("", UtcOffset(5 * 3600 + 30 * 60))));
+        assert_eq!(
+            utc_offset("-013015"),
+            Ok(("", UtcOffset(-(3600 + 30 * 60 + 15))))
+        );
+        assert!(utc_offset("-0000").is_err());
+        assert!(utc_offset("-000000").is_err());
+        assert!(utc_offset("0500").is_err());
+        assert!(utc_offset("+2400").is_err());
+        assert!(utc_offset("+0560").is_err());
+        assert!(utc_offset("+05").is_err());
+    }
+}
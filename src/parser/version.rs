@@ -0,0 +1,56 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::Property,
+    parser::{other_param, vervalue},
+};
+
+/// version    = "VERSION" verparam ":" vervalue CRLF
+///
+/// verparam   = *(";" other-param)
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.4>
+pub fn version(input: &str) -> IResult<&str, Property> {
+    (
+        nom::bytes::complete::tag_no_case("VERSION"),
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            other_param,
+        )),
+        nom::character::complete::char(':'),
+        vervalue,
+        nom::character::complete::line_ending,
+    )
+        .map(|(name, verparam, _, vervalue, _)| Property::new(name.to_owned(), verparam, vervalue))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{PropertyName, PropertyValue, Text};
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let input = "VERSION:2.0\r\n";
+        assert_eq!(
+            version(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("VERSION"),
+                    Default::default(),
+                    PropertyValue::Text(Text("2.0".to_owned())),
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(version("VERSION:\r\n").is_err());
+        assert!(version("VERSION:2.0").is_err());
+        assert!(version("INVALID:2.0\r\n").is_err());
+    }
+}
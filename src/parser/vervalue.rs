@@ -0,0 +1,47 @@
+use nom::{IResult, Parser};
+
+use crate::model::{PropertyValue, Text};
+
+/// vervalue   = "2.0"         ;This memo
+///            / maxver
+///            / (minver ";" maxver)
+///
+/// minver     = <A IANA-registered iCalendar version identifier>
+/// ;Minimum iCalendar version needed to parse the iCalendar object.
+///
+/// maxver     = <A IANA-registered iCalendar version identifier>
+/// ;Maximum iCalendar version needed to parse the iCalendar object.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.4>
+pub fn vervalue(input: &str) -> IResult<&str, PropertyValue> {
+    fn ver(input: &str) -> IResult<&str, &str> {
+        nom::bytes::complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '.')
+            .parse(input)
+    }
+
+    nom::combinator::recognize((
+        ver,
+        nom::combinator::opt((nom::character::complete::char(';'), ver)),
+    ))
+    .map(|s: &str| PropertyValue::Text(Text(s.to_owned())))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vervalue() {
+        assert_eq!(
+            vervalue("2.0"),
+            Ok(("", PropertyValue::Text(Text("2.0".to_owned()))))
+        );
+        assert_eq!(
+            vervalue("1.0;2.0"),
+            Ok(("", PropertyValue::Text(Text("1.0;2.0".to_owned()))))
+        );
+        assert!(vervalue("").is_err());
+        assert!(vervalue(";2.0").is_err());
+    }
+}
@@ -8,14 +8,14 @@ use crate::parser::vendorid;
 /// <https://datatracker.ietf.org/doc/html/rfc5545>
 pub fn x_name(input: &str) -> IResult<&str, String> {
     (
-        nom::bytes::tag("X-"),
+        nom::bytes::complete::tag_no_case("X-"),
         nom::combinator::opt((vendorid, nom::character::complete::char('-'))),
         nom::multi::many1(nom::character::complete::satisfy(|c| {
             c.is_ascii_alphanumeric() || c == '-'
         })),
     )
         .map(|(x_, vendorid, chars)| {
-            vec![
+            [
                 x_.to_owned(),
                 vendorid
                     .map(|(v, h)| format!("{}{}", v, h))
@@ -0,0 +1,65 @@
+use nom::{IResult, Parser};
+
+use crate::{
+    model::{Property, PropertyValue},
+    parser::{param, value, x_name},
+};
+
+/// x-prop = x-name *(";" icalparameter) ":" value CRLF
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.8.2>
+pub fn x_prop(input: &str) -> IResult<&str, Property> {
+    (
+        x_name,
+        nom::multi::many0(nom::sequence::preceded(
+            nom::character::complete::char(';'),
+            param,
+        )),
+        nom::character::complete::char(':'),
+        value,
+        nom::character::complete::line_ending,
+    )
+        .map(|(name, params, _, value, _)| Property::new(name, params, PropertyValue::XType(value)))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{ParameterName, ParameterValue, PropertyName};
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let input =
+            "X-ABC-MMSUBJ;VALUE=URI;FMTTYPE=audio/basic:http://www.example.org/mysubj.au\r\n";
+        assert_eq!(
+            x_prop(input),
+            Ok((
+                "",
+                Property(
+                    PropertyName::new("X-ABC-MMSUBJ"),
+                    [
+                        (
+                            ParameterName::new("VALUE"),
+                            ParameterValue(vec!["URI".to_owned()])
+                        ),
+                        (
+                            ParameterName::new("FMTTYPE"),
+                            ParameterValue(vec!["audio/basic".to_owned()])
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    PropertyValue::XType("http://www.example.org/mysubj.au".to_owned()),
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(x_prop("PRODID:-//ABC//EN\r\n").is_err());
+        assert!(x_prop("X-ABC:value").is_err());
+    }
+}